@@ -1,7 +1,7 @@
 use crate::components::code_block::CodeBlock;
 use dioxus::prelude::*;
 use dioxus_motion::{animations::utils::Animatable, prelude::*};
-use easer::functions::Easing;
+use easer::functions::Easing as _;
 
 #[component]
 /// Renders an animation step layout.
@@ -78,7 +78,7 @@ fn BasicValueAnimation() -> Element {
                 1.0,
                 AnimationConfig::new(AnimationMode::Tween(Tween {
                     duration: std::time::Duration::from_millis(500),
-                    easing: easer::functions::Cubic::ease_in_out,
+                    easing: Easing::Function(easer::functions::Cubic::ease_in_out),
                 })),
             );
         } else {
@@ -86,7 +86,7 @@ fn BasicValueAnimation() -> Element {
                 0.0,
                 AnimationConfig::new(AnimationMode::Tween(Tween {
                     duration: std::time::Duration::from_millis(500),
-                    easing: easer::functions::Cubic::ease_in_out,
+                    easing: Easing::Function(easer::functions::Cubic::ease_in_out),
                 })),
             );
         }
@@ -537,7 +537,7 @@ use_effect(move || {
         1.0,
         AnimationConfig::new(AnimationMode::Tween(Tween {
             duration: Duration::from_millis(500),
-            easing: easer::functions::Cubic::ease_in_out,
+            easing: Easing::Function(easer::functions::Cubic::ease_in_out),
         })),
     );
 });
@@ -653,7 +653,7 @@ transform.animate_to(
     Transform::new(0.0, 0.0, 1.0, 0.0),
     AnimationConfig::new(AnimationMode::Tween(Tween {
         duration: Duration::from_millis(300),
-        easing: easer::functions::Cubic::ease_out,
+        easing: Easing::Function(easer::functions::Cubic::ease_out),
     })),
 );"#.to_string(),
                 TransformAnimation {}
@@ -813,7 +813,7 @@ fn AdvancedFeaturesAnimation() -> Element {
             1.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: Easing::Function(easer::functions::Cubic::ease_in_out),
             }))
             .with_loop(LoopMode::Infinite),
         );
@@ -834,7 +834,7 @@ fn AdvancedFeaturesAnimation() -> Element {
             1.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(1000),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: Easing::Function(easer::functions::Cubic::ease_in_out),
             }))
             .with_loop(LoopMode::Times(3))
             .with_on_complete(|| println!("Animation completed after 3 loops!")),
@@ -847,21 +847,21 @@ fn AdvancedFeaturesAnimation() -> Element {
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
+                easing: Easing::Function(easer::functions::Cubic::ease_out),
             })),
         );
         delayed_value.animate_to(
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
+                easing: Easing::Function(easer::functions::Cubic::ease_out),
             })),
         );
         callback_value.animate_to(
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(500),
-                easing: easer::functions::Cubic::ease_out,
+                easing: Easing::Function(easer::functions::Cubic::ease_out),
             })),
         );
     };
@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_motion::prelude::*;
-use easer::functions::Easing;
+use easer::functions::Easing as _;
 
 use crate::utils::router::Route;
 
@@ -46,7 +46,7 @@ pub fn NavBar() -> Element {
             1.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_millis(300),
-                easing: easer::functions::Cubic::ease_out,
+                easing: Easing::Function(easer::functions::Cubic::ease_out),
             })),
         );
     });
@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_motion::prelude::*;
-use easer::functions::Easing;
+use easer::functions::Easing as _;
 
 #[component]
 fn BouncingLetter(letter: char, delay: f32) -> Element {
@@ -17,7 +17,7 @@ fn BouncingLetter(letter: char, delay: f32) -> Element {
             },
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(1),
-                easing: easer::functions::Sine::ease_in_out,
+                easing: Easing::Function(easer::functions::Sine::ease_in_out),
             }))
             .with_loop(LoopMode::Infinite)
             .with_delay(delay),
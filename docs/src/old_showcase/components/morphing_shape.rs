@@ -51,7 +51,7 @@ pub fn MorphingShape(shapes: Vec<&'static str>, duration: f32) -> Element {
         // Start shape transition loop
         spawn(async move {
             loop {
-                Time::delay(Duration::from_secs_f32(duration)).await;
+                MotionTime.delay(Duration::from_secs_f32(duration)).await;
                 let next = (*current_shape.read() + 1) % shape_configs.len();
                 current_shape.set(next);
             }
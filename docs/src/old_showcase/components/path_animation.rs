@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_motion::prelude::*;
-use easer::functions::Easing;
+use easer::functions::Easing as _;
 
 #[component]
 pub fn PathAnimation(path: &'static str, duration: f32) -> Element {
@@ -12,7 +12,7 @@ pub fn PathAnimation(path: &'static str, duration: f32) -> Element {
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs_f32(duration),
-                easing: easer::functions::Cubic::ease_in_out,
+                easing: Easing::Function(easer::functions::Cubic::ease_in_out),
             }))
             .with_loop(LoopMode::Infinite),
         );
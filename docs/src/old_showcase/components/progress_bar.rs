@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_motion::prelude::*;
-use easer::functions::Easing;
+use easer::functions::Easing as _;
 
 #[component]
 pub fn ProgressBar(title: &'static str) -> Element {
@@ -11,7 +11,7 @@ pub fn ProgressBar(title: &'static str) -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(5),
-                easing: easer::functions::Sine::ease_in_out,
+                easing: Easing::Function(easer::functions::Sine::ease_in_out),
             }))
             .with_loop(LoopMode::Infinite),
         );
@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_motion::prelude::*;
-use easer::functions::Easing;
+use easer::functions::Easing as _;
 
 // A playful button that bounces on click
 #[component]
@@ -38,7 +38,7 @@ pub fn RotatingButton() -> Element {
             360.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(1),
-                easing: easer::functions::Back::ease_in_out,
+                easing: Easing::Function(easer::functions::Back::ease_in_out),
             })),
         );
 
@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_motion::prelude::*;
-use easer::functions::Easing;
+use easer::functions::Easing as _;
 
 #[component]
 pub fn TypewriterEffect(text: &'static str) -> Element {
@@ -14,7 +14,7 @@ pub fn TypewriterEffect(text: &'static str) -> Element {
             text_len,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs_f32(text_len * 0.1), // 0.1s per character
-                easing: easer::functions::Linear::ease_in_out,
+                easing: Easing::Function(easer::functions::Linear::ease_in_out),
             }))
             .with_loop(LoopMode::Infinite),
         );
@@ -24,7 +24,7 @@ pub fn TypewriterEffect(text: &'static str) -> Element {
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(1),
-                easing: easer::functions::Linear::ease_in_out,
+                easing: Easing::Function(easer::functions::Linear::ease_in_out),
             }))
             .with_loop(LoopMode::Infinite),
         );
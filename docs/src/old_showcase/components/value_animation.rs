@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_motion::prelude::*;
-use easer::functions::Easing;
+use easer::functions::Easing as _;
 
 #[component]
 pub fn ValueAnimationShowcase() -> Element {
@@ -11,7 +11,7 @@ pub fn ValueAnimationShowcase() -> Element {
             100.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(10),
-                easing: easer::functions::Sine::ease_in_out,
+                easing: Easing::Function(easer::functions::Sine::ease_in_out),
             })),
         );
     };
@@ -21,7 +21,7 @@ pub fn ValueAnimationShowcase() -> Element {
             0.0,
             AnimationConfig::new(AnimationMode::Tween(Tween {
                 duration: Duration::from_secs(3),
-                easing: easer::functions::Sine::ease_out,
+                easing: Easing::Function(easer::functions::Sine::ease_out),
             })),
         );
     };
@@ -0,0 +1,154 @@
+//! CSS `<easing-function>` parsing shared by dioxus-motion and its macros
+//!
+//! `Easing`'s `FromStr` builds curves from [`CssEasing`] at runtime, while
+//! `#[transition(easing = "...")]` turns it into constructor calls at compile time,
+//! so both accept exactly the same strings.
+
+/// Jump positions of CSS `steps()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepJump {
+    Start,
+    End,
+    None,
+    Both,
+}
+
+/// A parsed CSS easing function
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssEasing {
+    /// The `linear` keyword
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// `cubic-bezier(x1, y1, x2, y2)`
+    CubicBezier(f32, f32, f32, f32),
+    /// `steps(count, position)`, also `step-start` and `step-end`
+    Steps(u32, StepJump),
+    /// `linear(...)` stops as `(output, input)`, inputs as fractions rather than
+    /// percentages; missing inputs are spaced out when building the curve
+    LinearStops(Vec<(f32, Option<f32>)>),
+}
+
+impl CssEasing {
+    /// Parses CSS easing syntax: keywords, `cubic-bezier()`, `steps()` and `linear()`
+    ///
+    /// Returns `None` for anything else, including non-finite numbers.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        match s {
+            "linear" => return Some(Self::Linear),
+            "ease" => return Some(Self::Ease),
+            "ease-in" => return Some(Self::EaseIn),
+            "ease-out" => return Some(Self::EaseOut),
+            "ease-in-out" => return Some(Self::EaseInOut),
+            "step-start" => return Some(Self::Steps(1, StepJump::Start)),
+            "step-end" => return Some(Self::Steps(1, StepJump::End)),
+            _ => {}
+        }
+
+        let (name, args) = s.strip_suffix(')')?.split_once('(')?;
+        let args: Vec<&str> = args.split(',').map(str::trim).collect();
+
+        match name.trim() {
+            "cubic-bezier" => {
+                let values = args
+                    .iter()
+                    .map(|arg| number(arg))
+                    .collect::<Option<Vec<_>>>()?;
+                match values[..] {
+                    [x1, y1, x2, y2] => Some(Self::CubicBezier(x1, y1, x2, y2)),
+                    _ => None,
+                }
+            }
+            "steps" => {
+                let count = args
+                    .first()
+                    .and_then(|count| count.parse::<u32>().ok())
+                    .filter(|count| *count > 0)?;
+                let position = match args.get(1).copied() {
+                    None | Some("end") | Some("jump-end") => StepJump::End,
+                    Some("start") | Some("jump-start") => StepJump::Start,
+                    Some("jump-none") => StepJump::None,
+                    Some("jump-both") => StepJump::Both,
+                    Some(_) => return None,
+                };
+                Some(Self::Steps(count, position))
+            }
+            "linear" => {
+                let mut stops = Vec::with_capacity(args.len());
+                for arg in &args {
+                    // `<output> [<input>% [<input>%]]`, two inputs repeat the output
+                    let mut parts = arg.split_whitespace();
+                    let output = parts.next().and_then(number)?;
+                    let inputs = parts
+                        .map(|input| {
+                            input
+                                .strip_suffix('%')
+                                .and_then(number)
+                                .map(|input| input / 100.0)
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+                    match inputs[..] {
+                        [] => stops.push((output, None)),
+                        [input] => stops.push((output, Some(input))),
+                        [from, to] => {
+                            stops.push((output, Some(from)));
+                            stops.push((output, Some(to)));
+                        }
+                        _ => return None,
+                    }
+                }
+                if stops.len() < 2 {
+                    return None;
+                }
+                Some(Self::LinearStops(stops))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn number(value: &str) -> Option<f32> {
+    value.parse::<f32>().ok().filter(|value| value.is_finite())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(CssEasing::parse(" ease-out "), Some(CssEasing::EaseOut));
+        assert_eq!(
+            CssEasing::parse("cubic-bezier(0.4, 0, 0.2, 1)"),
+            Some(CssEasing::CubicBezier(0.4, 0.0, 0.2, 1.0))
+        );
+        assert_eq!(
+            CssEasing::parse("step-start"),
+            Some(CssEasing::Steps(1, StepJump::Start))
+        );
+        assert_eq!(
+            CssEasing::parse("linear(0, 0.5 25% 75%, 1)"),
+            Some(CssEasing::LinearStops(vec![
+                (0.0, None),
+                (0.5, Some(0.25)),
+                (0.5, Some(0.75)),
+                (1.0, None),
+            ]))
+        );
+
+        assert_eq!(CssEasing::parse("cubic-bezier(1, 2)"), None);
+        assert_eq!(CssEasing::parse("steps(0)"), None);
+        assert_eq!(CssEasing::parse("linear(0)"), None);
+        assert_eq!(CssEasing::parse("bounce"), None);
+    }
+
+    #[test]
+    fn test_rejects_non_finite_numbers() {
+        assert_eq!(CssEasing::parse("cubic-bezier(0, NaN, 1, 1)"), None);
+        assert_eq!(CssEasing::parse("linear(0, inf)"), None);
+        assert_eq!(CssEasing::parse("linear(0, 0.5 inf%, 1)"), None);
+    }
+}
@@ -0,0 +1,263 @@
+//! `#[derive(Animatable)]` implementation
+//!
+//! Generates a field-wise `Animatable` impl for structs whose fields are all
+//! `Animatable`. Supported field attributes:
+//! - `#[animatable(skip)]`: field is not animated; it keeps the value of the
+//!   starting state and snaps to the target once the animation completes
+//! - `#[animatable(with = path::to::fn)]`: custom interpolation function with the
+//!   signature `fn(&T, &T, f32) -> T`
+//! - `#[animatable(angle)]` / `#[animatable(angle = "degrees")]`: interpolate an
+//!   `f32` angle along the shortest path (radians by default)
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, LitStr, Member};
+
+enum AngleUnit {
+    Radians,
+    Degrees,
+}
+
+enum FieldMode {
+    Animated,
+    Skip,
+    With(syn::Path),
+    Angle(AngleUnit),
+}
+
+struct AnimatableField<'a> {
+    member: Member,
+    ty: &'a syn::Type,
+    mode: FieldMode,
+}
+
+fn parse_field_mode(field: &syn::Field) -> syn::Result<FieldMode> {
+    let mut mode = FieldMode::Animated;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("animatable"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                mode = FieldMode::Skip;
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                let value = meta.value()?;
+                // Accept both `with = path::to::fn` and `with = "path::to::fn"`
+                let path = if value.peek(LitStr) {
+                    value.parse::<LitStr>()?.parse::<syn::Path>()?
+                } else {
+                    value.parse::<syn::Path>()?
+                };
+                mode = FieldMode::With(path);
+                Ok(())
+            } else if meta.path.is_ident("angle") {
+                let unit = if meta.input.peek(syn::Token![=]) {
+                    let unit = meta.value()?.parse::<LitStr>()?;
+                    match unit.value().as_str() {
+                        "radians" => AngleUnit::Radians,
+                        "degrees" => AngleUnit::Degrees,
+                        _ => {
+                            return Err(syn::Error::new(
+                                unit.span(),
+                                "expected `\"radians\"` or `\"degrees\"`",
+                            ))
+                        }
+                    }
+                } else {
+                    AngleUnit::Radians
+                };
+                mode = FieldMode::Angle(unit);
+                Ok(())
+            } else {
+                Err(meta
+                    .error("unsupported animatable attribute, expected `skip`, `with` or `angle`"))
+            }
+        })?;
+    }
+
+    Ok(mode)
+}
+
+fn collect_fields(fields: &Fields) -> syn::Result<Vec<AnimatableField<'_>>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member = field
+                .ident
+                .clone()
+                .map(Member::Named)
+                .unwrap_or_else(|| Member::Unnamed(index.into()));
+            Ok(AnimatableField {
+                member,
+                ty: &field.ty,
+                mode: parse_field_mode(field)?,
+            })
+        })
+        .collect()
+}
+
+/// Builds `Self { a: .., b: .. }` or `Self(.., ..)` depending on the struct shape
+fn construct(fields: &Fields, values: &[TokenStream]) -> TokenStream {
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| &f.ident);
+            quote! { Self { #(#names: #values,)* } }
+        }
+        Fields::Unnamed(_) => quote! { Self(#(#values,)*) },
+        Fields::Unit => quote! { Self },
+    }
+}
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "Animatable can only be derived for structs",
+            ))
+        }
+    };
+    let animatable_fields = collect_fields(fields)?;
+    let animatable = quote! { ::dioxus_motion::animations::utils::Animatable };
+
+    let zero: Vec<_> = animatable_fields
+        .iter()
+        .map(|f| {
+            let ty = &f.ty;
+            match f.mode {
+                FieldMode::Skip => quote! { ::core::default::Default::default() },
+                _ => quote! { <#ty as #animatable>::zero() },
+            }
+        })
+        .collect();
+
+    let epsilons: Vec<_> = animatable_fields
+        .iter()
+        .filter(|f| !matches!(f.mode, FieldMode::Skip))
+        .map(|f| {
+            let ty = &f.ty;
+            quote! { <#ty as #animatable>::epsilon() }
+        })
+        .collect();
+    let epsilon_count = epsilons.len();
+
+    let magnitudes = animatable_fields
+        .iter()
+        .filter(|f| !matches!(f.mode, FieldMode::Skip))
+        .map(|f| {
+            let member = &f.member;
+            quote! { #animatable::magnitude(&self.#member).powi(2) }
+        });
+
+    // Component-wise arithmetic; skipped fields keep the value of `self`
+    let binary = |op: TokenStream| -> Vec<TokenStream> {
+        animatable_fields
+            .iter()
+            .map(|f| {
+                let member = &f.member;
+                match f.mode {
+                    FieldMode::Skip => quote! { self.#member },
+                    _ => quote! { #animatable::#op(&self.#member, &other.#member) },
+                }
+            })
+            .collect()
+    };
+    let add = binary(quote! { add });
+    let sub = binary(quote! { sub });
+
+    let scale: Vec<_> = animatable_fields
+        .iter()
+        .map(|f| {
+            let member = &f.member;
+            match f.mode {
+                FieldMode::Skip => quote! { self.#member },
+                _ => quote! { #animatable::scale(&self.#member, factor) },
+            }
+        })
+        .collect();
+
+    let interpolate: Vec<_> = animatable_fields
+        .iter()
+        .map(|f| {
+            let member = &f.member;
+            match &f.mode {
+                FieldMode::Animated => {
+                    quote! { #animatable::interpolate(&self.#member, &target.#member, t) }
+                }
+                FieldMode::Skip => quote! {
+                    if t < 1.0 { self.#member } else { target.#member }
+                },
+                FieldMode::With(path) => quote! { #path(&self.#member, &target.#member, t) },
+                FieldMode::Angle(unit) => {
+                    let half_turn = match unit {
+                        AngleUnit::Radians => quote! { ::core::f32::consts::PI },
+                        AngleUnit::Degrees => quote! { 180.0f32 },
+                    };
+                    // Same shortest-path handling as `Transform::interpolate`
+                    quote! {{
+                        let mut diff = target.#member - self.#member;
+                        if diff > #half_turn {
+                            diff -= 2.0 * #half_turn;
+                        } else if diff < -#half_turn {
+                            diff += 2.0 * #half_turn;
+                        }
+                        self.#member + diff * t
+                    }}
+                }
+            }
+        })
+        .collect();
+
+    let zero = construct(fields, &zero);
+    let add = construct(fields, &add);
+    let sub = construct(fields, &sub);
+    let scale = construct(fields, &scale);
+    let interpolate = construct(fields, &interpolate);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #animatable for #name #ty_generics #where_clause {
+            fn zero() -> Self {
+                #zero
+            }
+
+            fn epsilon() -> f32 {
+                // The finest field precision decides when the whole struct has settled
+                let epsilons: [f32; #epsilon_count] = [#(#epsilons,)*];
+                let epsilon = epsilons.into_iter().fold(f32::INFINITY, f32::min);
+                if epsilon.is_finite() {
+                    epsilon
+                } else {
+                    0.001
+                }
+            }
+
+            fn magnitude(&self) -> f32 {
+                (0.0f32 #(+ #magnitudes)*).sqrt()
+            }
+
+            fn scale(&self, factor: f32) -> Self {
+                #scale
+            }
+
+            fn add(&self, other: &Self) -> Self {
+                #add
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                #sub
+            }
+
+            fn interpolate(&self, target: &Self, t: f32) -> Self {
+                #interpolate
+            }
+        }
+    })
+}
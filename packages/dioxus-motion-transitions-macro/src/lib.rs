@@ -1,18 +1,26 @@
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{parse_macro_input, Attribute, Data, DataEnum, DeriveInput, Fields, Meta};
-
-fn get_transition_from_attrs(attrs: &[Attribute]) -> Option<String> {
-    attrs
-        .iter()
-        .find(|attr| attr.path().is_ident("transition"))
-        .and_then(|attr| {
-            if let Ok(Meta::Path(path)) = attr.parse_args::<Meta>() {
-                path.get_ident().map(|ident| ident.to_string())
-            } else {
-                None
-            }
-        })
+use quote::quote;
+use syn::{parse_macro_input, Data, DataEnum, DeriveInput, Fields};
+
+use transition::TransitionAttr;
+
+mod animatable;
+mod transition;
+
+// Pattern matching a variant regardless of its fields
+fn variant_pattern(variant: &syn::Variant) -> proc_macro2::TokenStream {
+    let variant_ident = &variant.ident;
+    match &variant.fields {
+        Fields::Named(fields) => {
+            let field_patterns = fields.named.iter().map(|f| {
+                let name = &f.ident;
+                quote! { #name: _ }
+            });
+            quote! { Self::#variant_ident { #(#field_patterns,)* } }
+        }
+        Fields::Unnamed(_) => quote! { Self::#variant_ident(..) },
+        Fields::Unit => quote! { Self::#variant_ident {} },
+    }
 }
 
 // Helper to extract layout nesting information from enum variants
@@ -47,7 +55,17 @@ fn get_layout_depth(variants: &[&syn::Variant]) -> Vec<(syn::Ident, usize)> {
     layout_depth
 }
 
-#[proc_macro_derive(MotionTransitions, attributes(transition, layout, end_layout))]
+/// Implements `AnimatableRoute` for a router enum.
+///
+/// Each variant picks its transition with `#[transition(Variant)]` (default: `Fade`), or
+/// `#[transition(path::to::function)]` for a function returning a custom `TransitionConfig`,
+/// optionally overriding the timing with `duration = 300`, `easing = "ease-out"` or
+/// `spring = gentle`. Navigating back from a variant plays its transition reversed,
+/// unless `#[back_transition(...)]` names another one or overrides the timing.
+#[proc_macro_derive(
+    MotionTransitions,
+    attributes(transition, back_transition, layout, end_layout)
+)]
 pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -78,31 +96,62 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
         }
     });
 
-    let transition_match_arms = variants.iter().map(|variant| {
-        let variant_ident = &variant.ident;
-        let transition = get_transition_from_attrs(&variant.attrs)
-            .map(|t| format_ident!("{}", t))
-            .unwrap_or(format_ident!("Fade"));
+    let parse_all = |name: &str| {
+        variants
+            .iter()
+            .map(|variant| TransitionAttr::parse(&variant.attrs, name))
+            .collect::<syn::Result<Vec<_>>>()
+    };
+    let transitions = match parse_all("transition") {
+        Ok(transitions) => transitions,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let back_transitions = match parse_all("back_transition") {
+        Ok(transitions) => transitions,
+        Err(err) => return err.into_compile_error().into(),
+    };
 
-        match &variant.fields {
-            Fields::Named(fields) => {
-                let field_patterns = fields.named.iter().map(|f| {
-                    let name = &f.ident;
-                    quote! { #name: _ }
-                });
-                quote! {
-                    Self::#variant_ident { #(#field_patterns,)* } => TransitionVariant::#transition
-                }
-            }
-            Fields::Unnamed(_) => {
-                quote! { Self::#variant_ident(..) => TransitionVariant::#transition }
-            }
-            Fields::Unit => {
-                quote! { Self::#variant_ident {} => TransitionVariant::#transition }
-            }
-        }
+    let transition_match_arms = variants.iter().zip(&transitions).map(|(variant, attr)| {
+        let pattern = variant_pattern(variant);
+        let transition = attr
+            .as_ref()
+            .and_then(TransitionAttr::variant)
+            .unwrap_or(quote! { TransitionVariant::Fade });
+        quote! { #pattern => #transition }
     });
 
+    // Only variants overriding the timing need an arm
+    let transition_config_match_arms =
+        variants
+            .iter()
+            .zip(&transitions)
+            .filter_map(|(variant, attr)| {
+                let pattern = variant_pattern(variant);
+                let mode = attr.as_ref()?.mode()?;
+                Some(quote! {
+                    #pattern => self.get_transition().get_config().with_mode(#mode)
+                })
+            });
+
+    // Variants without `#[back_transition]` reverse their forward transition
+    let back_transition_config_match_arms =
+        variants
+            .iter()
+            .zip(&back_transitions)
+            .filter_map(|(variant, attr)| {
+                let attr = attr.as_ref()?;
+                let pattern = variant_pattern(variant);
+                let config = match attr.variant() {
+                    Some(transition) => quote! { #transition.get_config() },
+                    None => quote! { self.get_transition_config().reversed() },
+                };
+                let config = match attr.mode() {
+                    Some(mode) => quote! { #config.with_mode(#mode) },
+                    None => config,
+                };
+                Some(quote! { #pattern => #config })
+            });
+
     // Generate layout depth match arms
     let layout_depths = get_layout_depth(&variants.iter().collect::<Vec<_>>());
     let layout_depth_match_arms =
@@ -140,6 +189,20 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
                 }
             }
 
+            fn get_transition_config(&self) -> TransitionConfig {
+                match self {
+                    #(#transition_config_match_arms,)*
+                    _ => self.get_transition().get_config(),
+                }
+            }
+
+            fn get_back_transition_config(&self) -> TransitionConfig {
+                match self {
+                    #(#back_transition_config_match_arms,)*
+                    _ => self.get_transition_config().reversed(),
+                }
+            }
+
             fn get_component(&self) -> Element {
                 match self {
                     #(#component_match_arms,)*
@@ -158,3 +221,18 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Derives `Animatable` for a struct whose fields are all `Animatable`.
+///
+/// Every operation is applied field by field. Fields can be customised with:
+/// - `#[animatable(skip)]` to leave a field out of the animation (requires `Default`)
+/// - `#[animatable(with = path::to::fn)]` to interpolate with `fn(&T, &T, f32) -> T`
+/// - `#[animatable(angle)]` or `#[animatable(angle = "degrees")]` to take the shortest
+///   path around the circle, like `Transform` does for its rotation
+#[proc_macro_derive(Animatable, attributes(animatable))]
+pub fn derive_animatable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    animatable::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
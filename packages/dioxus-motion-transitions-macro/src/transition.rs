@@ -0,0 +1,159 @@
+//! `#[transition(...)]` / `#[back_transition(...)]` attribute parsing for
+//! `#[derive(MotionTransitions)]`
+//!
+//! The attribute names a `TransitionVariant` (optional for `back_transition`, which
+//! then reverses the forward transition) or a path to a function returning a custom
+//! `TransitionConfig`, like `my_transition` or `crate::transitions::branded`. It can be
+//! followed by timing overrides:
+//! - `duration = 300`: tween lasting that many milliseconds
+//! - `easing = "ease-out"`: tween easing in CSS syntax, checked at compile time, or
+//!   any expression evaluating to an `Easing`
+//! - `spring = gentle`: spring preset (`gentle`, `wobbly`, `stiff`, `slow`,
+//!   `molasses`), or any expression evaluating to a `Spring`
+
+use dioxus_motion_easing_syntax::{CssEasing, StepJump};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Expr, ExprLit, ExprPath, Lit, LitInt, LitStr, Path};
+
+enum Timing {
+    Default,
+    Tween {
+        duration: Option<LitInt>,
+        easing: Option<TokenStream>,
+    },
+    Spring(Expr),
+}
+
+pub struct TransitionAttr {
+    variant: Option<Path>,
+    timing: Timing,
+}
+
+impl TransitionAttr {
+    /// Parses the attribute called `name`, if present
+    pub fn parse(attrs: &[Attribute], name: &str) -> syn::Result<Option<Self>> {
+        let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident(name)) else {
+            return Ok(None);
+        };
+
+        let mut variant = None;
+        let mut duration = None;
+        let mut easing = None;
+        let mut spring = None;
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("duration") {
+                duration = Some(meta.value()?.parse::<LitInt>()?);
+            } else if meta.path.is_ident("easing") {
+                easing = Some(match meta.value()?.parse::<Expr>()? {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(css), ..
+                    }) => css_easing(&css)?,
+                    easing => quote! { #easing },
+                });
+            } else if meta.path.is_ident("spring") {
+                spring = Some(meta.value()?.parse::<Expr>()?);
+            } else if variant.is_none() && !meta.input.peek(syn::Token![=]) {
+                variant = Some(meta.path.clone());
+            } else {
+                return Err(
+                    meta.error("expected a transition variant, `duration`, `easing` or `spring`")
+                );
+            }
+            Ok(())
+        })?;
+
+        let timing = match (spring, duration, easing) {
+            (Some(spring), None, None) => Timing::Spring(spring),
+            (Some(spring), _, _) => {
+                return Err(syn::Error::new_spanned(
+                    spring,
+                    "`spring` can't be combined with `duration` or `easing`",
+                ))
+            }
+            (None, None, None) => Timing::Default,
+            (None, duration, easing) => Timing::Tween { duration, easing },
+        };
+
+        Ok(Some(Self { variant, timing }))
+    }
+
+    /// `TransitionVariant` named by the attribute, if any
+    ///
+    /// A single capitalised name is a preset, anything else calls a function
+    /// returning a custom `TransitionConfig`.
+    pub fn variant(&self) -> Option<TokenStream> {
+        let path = self.variant.as_ref()?;
+        let is_preset = path
+            .get_ident()
+            .is_some_and(|ident| ident.to_string().starts_with(char::is_uppercase));
+        if is_preset {
+            Some(quote! { TransitionVariant::#path })
+        } else {
+            Some(quote! { TransitionVariant::Custom(Box::new(#path())) })
+        }
+    }
+
+    /// `AnimationMode` overriding the variant's timing, if any
+    pub fn mode(&self) -> Option<TokenStream> {
+        match &self.timing {
+            Timing::Default => None,
+            Timing::Spring(spring) => {
+                let spring = match spring {
+                    // A bare name refers to a preset
+                    Expr::Path(ExprPath { path, .. }) if path.get_ident().is_some() => {
+                        quote! { Spring::#path() }
+                    }
+                    spring => quote! { #spring },
+                };
+                Some(quote! { AnimationMode::Spring(#spring) })
+            }
+            Timing::Tween { duration, easing } => {
+                let mut tween = match duration {
+                    Some(millis) => quote! { Tween::new(Duration::from_millis(#millis)) },
+                    None => quote! { Tween::default() },
+                };
+                if let Some(easing) = easing {
+                    tween = quote! { #tween.with_timing(#easing) };
+                }
+                Some(quote! { AnimationMode::Tween(#tween) })
+            }
+        }
+    }
+}
+
+/// `Easing` constructor for a CSS timing function, parsed like `Easing`'s `FromStr`
+///
+/// Checking the syntax here reports typos at compile time rather than on navigation.
+fn css_easing(css: &LitStr) -> syn::Result<TokenStream> {
+    let value = css.value();
+    let easing = CssEasing::parse(&value)
+        .ok_or_else(|| syn::Error::new_spanned(css, format!("invalid CSS easing `{value}`")))?;
+    Ok(match easing {
+        CssEasing::Linear => quote! { Easing::default() },
+        CssEasing::Ease => quote! { Easing::ease() },
+        CssEasing::EaseIn => quote! { Easing::ease_in() },
+        CssEasing::EaseOut => quote! { Easing::ease_out() },
+        CssEasing::EaseInOut => quote! { Easing::ease_in_out() },
+        CssEasing::CubicBezier(x1, y1, x2, y2) => {
+            quote! { Easing::cubic_bezier(#x1, #y1, #x2, #y2) }
+        }
+        CssEasing::Steps(count, jump) => {
+            let position = match jump {
+                StepJump::Start => quote! { JumpStart },
+                StepJump::End => quote! { JumpEnd },
+                StepJump::None => quote! { JumpNone },
+                StepJump::Both => quote! { JumpBoth },
+            };
+            quote! { Easing::steps(#count, StepPosition::#position) }
+        }
+        CssEasing::LinearStops(stops) => {
+            let stops = stops.iter().map(|(output, input)| match input {
+                Some(input) => quote! { (#output, Some(#input)) },
+                None => quote! { (#output, None) },
+            });
+            quote! { Easing::linear_stops([#(#stops),*]) }
+        }
+    })
+}
@@ -0,0 +1,201 @@
+//! Decay animation module
+//!
+//! Provides inertia for fling and scroll-momentum effects: the value glides on from
+//! a starting velocity and slows down exponentially. The resting point follows from
+//! the velocity instead of a target, and optional bounds hand the value over to a
+//! bounce spring once it runs past them.
+
+use instant::Duration;
+
+use crate::animations::spring::Spring;
+
+/// Configuration for decay (inertia) animations
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+///
+/// // Fling a scroll offset, bouncing back at the ends of the list
+/// let fling = Decay::new(-1500.0).with_bounds(-2000.0, 0.0);
+/// let config = AnimationConfig::new(AnimationMode::Decay(fling));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decay {
+    /// Starting velocity in units per second (default: 0.0)
+    /// Signed for one-dimensional values, pointing towards the animation target otherwise
+    pub velocity: f32,
+
+    /// Scales how far the value travels (default: 0.8)
+    pub power: f32,
+
+    /// Seconds for the velocity to drop to ~37% (default: 0.35)
+    /// Higher values glide further and longer
+    pub time_constant: f32,
+
+    /// Distance from the resting point at which the decay ends (default: 0.5)
+    pub rest_delta: f32,
+
+    /// Lower bound, only applies to one-dimensional values
+    pub min: Option<f32>,
+
+    /// Upper bound, only applies to one-dimensional values
+    pub max: Option<f32>,
+
+    /// Spring taking over once a bound is reached
+    pub bounce: Spring,
+}
+
+impl Default for Decay {
+    fn default() -> Self {
+        Self {
+            velocity: 0.0,
+            power: 0.8,
+            time_constant: 0.35,
+            rest_delta: 0.5,
+            min: None,
+            max: None,
+            bounce: Spring {
+                stiffness: 500.0,
+                damping: 10.0,
+                mass: 1.0,
+                velocity: 0.0,
+            },
+        }
+    }
+}
+
+impl Decay {
+    /// Creates a decay starting at `velocity`
+    pub fn new(velocity: f32) -> Self {
+        Self {
+            velocity,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_power(mut self, power: f32) -> Self {
+        self.power = power;
+        self
+    }
+
+    pub fn with_time_constant(mut self, time_constant: f32) -> Self {
+        self.time_constant = time_constant;
+        self
+    }
+
+    pub fn with_rest_delta(mut self, rest_delta: f32) -> Self {
+        self.rest_delta = rest_delta;
+        self
+    }
+
+    /// Clamps the glide between `min` and `max`
+    pub fn with_bounds(mut self, min: f32, max: f32) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    pub fn with_bounce(mut self, bounce: Spring) -> Self {
+        self.bounce = bounce;
+        self
+    }
+
+    /// Distance travelled before coming to rest when starting at `speed`
+    pub fn travel(&self, speed: f32) -> f32 {
+        speed * self.power * self.time_constant
+    }
+
+    /// Time until the value is within `rest_delta` of its resting point
+    pub fn settling_time(&self) -> Duration {
+        let travel = self.travel(self.velocity).abs();
+        if travel <= self.rest_delta || self.time_constant <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs_f32(self.time_constant * (travel / self.rest_delta).ln())
+    }
+
+    /// Bound `position` has run past, if any
+    pub(crate) fn crossed_bound(&self, position: f32) -> Option<f32> {
+        match (self.min, self.max) {
+            (Some(min), _) if position < min => Some(min),
+            (_, Some(max)) if position > max => Some(max),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{AnimationConfig, AnimationMode};
+    use crate::Motion;
+
+    fn config(decay: Decay) -> AnimationConfig {
+        AnimationConfig::new(AnimationMode::Decay(decay))
+    }
+
+    #[test]
+    fn test_decay_target_from_velocity() {
+        let decay = Decay::new(1000.0);
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(0.0, config(decay));
+
+        let rest = decay.travel(1000.0);
+        assert_eq!(rest, 280.0);
+
+        // Slows down while gliding towards the resting point
+        motion.update(0.1);
+        let first = motion.value();
+        motion.update(0.1);
+        assert!(motion.value() - first < first);
+
+        for _ in 0..60 {
+            motion.update(0.05);
+        }
+        assert_eq!(motion.value(), rest);
+        assert!(!motion.is_running());
+    }
+
+    #[test]
+    fn test_decay_uses_explicit_velocity() {
+        let mut motion = Motion::new(100.0f32);
+        motion.animate_to_with_velocity(0.0, config(Decay::default()), -500.0);
+        motion.update(0.1);
+        assert!(motion.value() < 100.0);
+        assert!(motion.velocity() < 0.0);
+    }
+
+    #[test]
+    fn test_decay_bounces_at_bound() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(0.0, config(Decay::new(2000.0).with_bounds(-100.0, 100.0)));
+
+        let mut peak = 0.0f32;
+        for _ in 0..300 {
+            motion.update(1.0 / 60.0);
+            peak = peak.max(motion.value());
+        }
+        // Overshoots a little, then the bounce spring settles on the bound
+        assert!(peak > 100.0 && peak < 280.0 * 2.0);
+        assert!((motion.value() - 100.0).abs() < 0.01);
+        assert!(!motion.is_running());
+    }
+
+    #[test]
+    fn test_decay_starting_out_of_bounds() {
+        let mut motion = Motion::new(150.0f32);
+        motion.animate_to(0.0, config(Decay::default().with_bounds(0.0, 100.0)));
+        for _ in 0..300 {
+            motion.update(1.0 / 60.0);
+        }
+        assert!((motion.value() - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_decay_settling_time() {
+        assert_eq!(Decay::new(0.0).settling_time(), Duration::ZERO);
+        let decay = Decay::new(1000.0);
+        let expected = 0.35 * (280.0f32 / 0.5).ln();
+        assert!((decay.settling_time().as_secs_f32() - expected).abs() < 1e-3);
+    }
+}
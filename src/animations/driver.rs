@@ -0,0 +1,199 @@
+//! Frame driver module
+//!
+//! A single loop per [Clock] ticks every active motion, instead of one loop per hook.
+//! It runs one frame at a time (one `requestAnimationFrame` on web) while something is
+//! animating and sleeps without any timer once everything has settled, until a motion
+//! starts again.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::{Rc, Weak};
+use std::task::{Context, Poll, Waker};
+
+use dioxus::prelude::*;
+use instant::Duration;
+
+use crate::animations::platform::{Clock, TimeProvider};
+
+type Ticker = Box<dyn FnMut(f32) -> bool>;
+
+#[derive(Default)]
+struct DriverState {
+    tickers: Vec<(usize, Ticker)>,
+    next_id: usize,
+    awake: bool,
+    started: bool,
+    waker: Option<Waker>,
+}
+
+impl Drop for DriverState {
+    fn drop(&mut self) {
+        // Lets the sleeping loop notice it's no longer needed
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Loop ticking every motion registered on a [Clock]
+#[derive(Clone, Default)]
+pub(crate) struct FrameDriver {
+    state: Rc<RefCell<DriverState>>,
+}
+
+impl FrameDriver {
+    /// Adds a ticker called with the frame time in seconds until it returns false
+    ///
+    /// The ticker goes back to sleep with the driver, [FrameDriver::wake] it when its
+    /// animation starts again.
+    pub(crate) fn register(&self, ticker: impl FnMut(f32) -> bool + 'static) -> usize {
+        let mut state = self.state.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.tickers.push((id, Box::new(ticker)));
+        id
+    }
+
+    pub(crate) fn unregister(&self, id: usize) {
+        self.state
+            .borrow_mut()
+            .tickers
+            .retain(|(ticker, _)| *ticker != id);
+    }
+
+    /// Starts ticking, spawning the loop on first use
+    pub(crate) fn wake(&self, clock: &Clock) {
+        let mut state = self.state.borrow_mut();
+        if !state.started {
+            state.started = true;
+            let driver = Rc::downgrade(&self.state);
+            spawn_forever(run(driver, clock.detached()));
+        }
+        if !state.awake {
+            state.awake = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_awake(&self) -> bool {
+        self.state.borrow().awake
+    }
+}
+
+/// Ticks every registered ticker, returning whether any of them is still active
+fn tick(state: &Rc<RefCell<DriverState>>, dt: f32) -> bool {
+    // Tickers run outside the borrow as they may register new ones
+    let mut tickers = std::mem::take(&mut state.borrow_mut().tickers);
+    let mut active = false;
+    for (_, ticker) in tickers.iter_mut() {
+        active |= ticker(dt);
+    }
+
+    let mut state = state.borrow_mut();
+    tickers.append(&mut state.tickers);
+    state.tickers = tickers;
+    if !active {
+        state.awake = false;
+    }
+    active
+}
+
+async fn run(driver: Weak<RefCell<DriverState>>, clock: Clock) {
+    loop {
+        Wake {
+            driver: driver.clone(),
+        }
+        .await;
+        if driver.strong_count() == 0 {
+            return;
+        }
+
+        let mut last_frame = clock.now();
+        let mut running_frames = 0u32;
+        let mut dt = 0.0;
+        loop {
+            clock.delay(frame_delay(running_frames, dt)).await;
+            // Every clock using this driver is gone
+            let Some(state) = driver.upgrade() else {
+                return;
+            };
+
+            let now = clock.now();
+            dt = (now.duration_since(last_frame).as_secs_f32()).min(0.1);
+            last_frame = now;
+            running_frames += 1;
+            if !tick(&state, dt) {
+                break;
+            }
+        }
+    }
+}
+
+/// Time to wait for the next frame
+#[cfg(feature = "web")]
+fn frame_delay(_running_frames: u32, _dt: f32) -> Duration {
+    // Short enough to be scheduled with requestAnimationFrame
+    Duration::from_millis(8)
+}
+
+/// Time to wait for the next frame
+#[cfg(not(feature = "web"))]
+fn frame_delay(running_frames: u32, dt: f32) -> Duration {
+    match running_frames {
+        // Higher frame rate for the first ~200 frames for smooth starts
+        0..=200 => Duration::from_micros(8333), // ~120fps
+        _ => match dt {
+            x if x < 0.005 => Duration::from_millis(8),  // ~120fps
+            x if x < 0.011 => Duration::from_millis(16), // ~60fps
+            _ => Duration::from_millis(33),              // ~30fps
+        },
+    }
+}
+
+/// Pends until the driver is woken up
+struct Wake {
+    driver: Weak<RefCell<DriverState>>,
+}
+
+impl Future for Wake {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let Some(state) = self.driver.upgrade() else {
+            return Poll::Ready(());
+        };
+        let mut state = state.borrow_mut();
+        if state.awake {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Ticks `ticker` with the frame time every frame while `active` returns true
+///
+/// `active` is tracked like in an effect: the frame driver wakes up whenever a signal
+/// it reads changes and it returns true.
+pub(crate) fn use_frame_ticker(
+    clock: Clock,
+    active: impl Fn() -> bool + 'static,
+    ticker: impl FnMut(f32) -> bool + 'static,
+) {
+    let driver = clock.driver();
+    let id = use_hook(|| driver.register(ticker));
+    use_drop({
+        let driver = driver.clone();
+        move || driver.unregister(id)
+    });
+    use_effect(move || {
+        if active() {
+            driver.wake(&clock);
+        }
+    });
+}
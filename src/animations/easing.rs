@@ -0,0 +1,585 @@
+//! Easing module
+//!
+//! Provides `Easing`, the timing curve of a tween. Besides plain `easer`-style
+//! functions it supports the parametrised CSS timing functions `cubic-bezier()`,
+//! `steps()` and `linear()`, which can be parsed straight from their CSS syntax and
+//! exported back to it. Springs can be sampled into a `linear()` curve to get a
+//! fixed-duration tween that moves like a spring.
+
+use std::{fmt, str::FromStr, sync::Arc};
+
+use dioxus_motion_easing_syntax::{CssEasing, StepJump};
+
+use crate::animations::spring::Spring;
+
+/// Timing curve mapping linear progress (0.0-1.0) to eased progress
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+///
+/// let material = Easing::cubic_bezier(0.4, 0.0, 0.2, 1.0);
+/// let parsed: Easing = "cubic-bezier(0.4, 0, 0.2, 1)".parse().unwrap();
+/// assert_eq!(material, parsed);
+///
+/// let tween = Tween::new(Duration::from_millis(300)).with_timing(material);
+/// ```
+#[derive(Debug, Clone)]
+pub enum Easing {
+    /// An `easer`-style function taking `(t, b, c, d)`
+    Function(fn(f32, f32, f32, f32) -> f32),
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`
+    CubicBezier(CubicBezier),
+    /// CSS `steps(count, position)`
+    Steps(u32, StepPosition),
+    /// CSS `linear(...)`, as `(input, output)` points sorted by input
+    Linear(Arc<[(f32, f32)]>),
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Self::linear([0.0, 1.0])
+    }
+}
+
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Function(a), Self::Function(b)) => std::ptr::fn_addr_eq(*a, *b),
+            (Self::CubicBezier(a), Self::CubicBezier(b)) => a == b,
+            (Self::Steps(a, a_position), Self::Steps(b, b_position)) => {
+                a == b && a_position == b_position
+            }
+            (Self::Linear(a), Self::Linear(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl From<CubicBezier> for Easing {
+    fn from(curve: CubicBezier) -> Self {
+        Self::CubicBezier(curve)
+    }
+}
+
+impl Easing {
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`; `x1` and `x2` are clamped to 0.0-1.0
+    pub fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self::CubicBezier(CubicBezier::new(x1, y1, x2, y2))
+    }
+
+    /// CSS `ease`
+    pub fn ease() -> Self {
+        Self::cubic_bezier(0.25, 0.1, 0.25, 1.0)
+    }
+
+    /// CSS `ease-in`
+    pub fn ease_in() -> Self {
+        Self::cubic_bezier(0.42, 0.0, 1.0, 1.0)
+    }
+
+    /// CSS `ease-out`
+    pub fn ease_out() -> Self {
+        Self::cubic_bezier(0.0, 0.0, 0.58, 1.0)
+    }
+
+    /// CSS `ease-in-out`
+    pub fn ease_in_out() -> Self {
+        Self::cubic_bezier(0.42, 0.0, 0.58, 1.0)
+    }
+
+    /// CSS `steps(count, position)`
+    pub fn steps(count: u32, position: StepPosition) -> Self {
+        // `jump-none` needs two steps to have anything to jump between
+        let min = if position == StepPosition::JumpNone {
+            2
+        } else {
+            1
+        };
+        Self::Steps(count.max(min), position)
+    }
+
+    /// CSS `linear()` with evenly spaced outputs, e.g. `linear(0, 0.25, 1)`
+    pub fn linear(outputs: impl IntoIterator<Item = f32>) -> Self {
+        Self::linear_stops(outputs.into_iter().map(|output| (output, None)))
+    }
+
+    /// CSS `linear()` with optional input positions, e.g. `linear(0, 0.25 75%, 1)`
+    ///
+    /// Stops are `(output, input)`. Like CSS, a missing first input is 0.0, a missing
+    /// last input 1.0, inputs never go backwards, and the remaining missing inputs are
+    /// spread evenly between their neighbours.
+    pub fn linear_stops(stops: impl IntoIterator<Item = (f32, Option<f32>)>) -> Self {
+        let mut stops: Vec<(f32, Option<f32>)> = stops.into_iter().collect();
+        if stops.is_empty() {
+            stops = vec![(0.0, None), (1.0, None)];
+        }
+
+        let last = stops.len() - 1;
+        if let Some(first) = stops.first_mut() {
+            first.1.get_or_insert(0.0);
+        }
+        if let Some(end) = stops.last_mut().filter(|_| last > 0) {
+            end.1.get_or_insert(1.0);
+        }
+
+        let mut max_input = f32::NEG_INFINITY;
+        for stop in &mut stops {
+            if let Some(input) = stop.1.as_mut() {
+                *input = input.max(max_input);
+                max_input = *input;
+            }
+        }
+
+        // Spread runs of missing inputs between the known ones around them
+        let mut points = Vec::with_capacity(stops.len());
+        let mut index = 0;
+        while index < stops.len() {
+            let (output, input) = stops[index];
+            if let Some(input) = input {
+                points.push((input, output));
+                index += 1;
+                continue;
+            }
+            let run_end = stops[index..]
+                .iter()
+                .position(|stop| stop.1.is_some())
+                .map_or(stops.len(), |offset| index + offset);
+            let from = points.last().map_or(0.0, |point: &(f32, f32)| point.0);
+            let to = stops.get(run_end).and_then(|stop| stop.1).unwrap_or(from);
+            let count = (run_end - index + 1) as f32;
+            for (step, stop) in stops[index..run_end].iter().enumerate() {
+                let input = from + (to - from) * (step as f32 + 1.0) / count;
+                points.push((input, stop.0));
+            }
+            index = run_end;
+        }
+
+        Self::Linear(points.into())
+    }
+
+    /// Samples a spring going from 0.0 to 1.0 into a `linear()` curve
+    ///
+    /// The curve spans the spring's [`Spring::settling_time`]; pair it with that
+    /// duration to move exactly like the spring, or any other duration to squeeze or
+    /// stretch it. `Spring::velocity` is ignored since the curve has no distance.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// let spring = Spring::wobbly();
+    /// let tween = Tween::new(spring.settling_time()).with_timing(Easing::spring(spring));
+    /// // The same curve for a CSS transition
+    /// let css = tween.easing.to_css();
+    /// ```
+    pub fn spring(spring: Spring) -> Self {
+        Self::spring_with_samples(spring, 60)
+    }
+
+    /// Like [`Easing::spring`] with an explicit number of sample points (at least 2)
+    pub fn spring_with_samples(spring: Spring, samples: usize) -> Self {
+        let samples = samples.max(2);
+        let duration = spring.settling_time().as_secs_f32();
+        let last = (samples - 1) as f32;
+
+        let mut points: Vec<(f32, f32)> = (0..samples)
+            .map(|index| {
+                let input = index as f32 / last;
+                // Starting one unit away from the target at rest
+                let output = 1.0 - spring.response(input * duration).position.0;
+                (input, output)
+            })
+            .collect();
+        // Settled is close enough, end exactly on the target
+        if let Some(end) = points.last_mut() {
+            end.1 = 1.0;
+        }
+        Self::Linear(points.into())
+    }
+
+    /// The easing in CSS syntax, `None` for `easer` functions
+    pub fn to_css(&self) -> Option<String> {
+        match self {
+            Self::Function(_) => None,
+            Self::CubicBezier(curve) => Some(format!(
+                "cubic-bezier({}, {}, {}, {})",
+                css_number(curve.x1),
+                css_number(curve.y1),
+                css_number(curve.x2),
+                css_number(curve.y2)
+            )),
+            Self::Steps(count, position) => {
+                let position = match position {
+                    StepPosition::JumpStart => "jump-start",
+                    StepPosition::JumpEnd => "jump-end",
+                    StepPosition::JumpNone => "jump-none",
+                    StepPosition::JumpBoth => "jump-both",
+                };
+                Some(format!("steps({count}, {position})"))
+            }
+            Self::Linear(points) => {
+                let stops: Vec<String> = points
+                    .iter()
+                    .map(|(input, output)| {
+                        format!("{} {}%", css_number(*output), css_number(input * 100.0))
+                    })
+                    .collect();
+                Some(format!("linear({})", stops.join(", ")))
+            }
+        }
+    }
+
+    /// Eased progress at linear progress `t` (0.0-1.0)
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Self::Function(function) => function(t, 0.0, 1.0, 1.0),
+            Self::CubicBezier(curve) => curve.apply(t),
+            Self::Steps(count, position) => steps(t, *count, *position),
+            Self::Linear(points) => linear(points, t),
+        }
+    }
+}
+
+// Shortest CSS number with up to four decimals
+fn css_number(value: f32) -> String {
+    let formatted = format!("{value:.4}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        "" | "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+fn steps(t: f32, count: u32, position: StepPosition) -> f32 {
+    let count = count as f32;
+    let mut step = (t * count).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => count,
+        StepPosition::JumpBoth => count + 1.0,
+        StepPosition::JumpNone => count - 1.0,
+    };
+    // Keep the output inside 0.0-1.0 for progress inside 0.0-1.0
+    if t >= 0.0 && step < 0.0 {
+        step = 0.0;
+    }
+    if t <= 1.0 && step > jumps {
+        step = jumps;
+    }
+    step / jumps
+}
+
+fn linear(points: &[(f32, f32)], t: f32) -> f32 {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return t;
+    };
+    let index = points.partition_point(|(input, _)| *input <= t);
+    if index == 0 {
+        return first.1;
+    }
+    if index == points.len() {
+        return last.1;
+    }
+    let (x0, y0) = points[index - 1];
+    let (x1, y1) = points[index];
+    y0 + (y1 - y0) * (t - x0) / (x1 - x0)
+}
+
+/// Where the jumps of a `steps()` easing happen
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum StepPosition {
+    /// Jump at the start of each step (CSS `start`)
+    JumpStart,
+    /// Jump at the end of each step (CSS `end`)
+    #[default]
+    JumpEnd,
+    /// No jump at either end, holding 0.0 and 1.0 for a step each
+    JumpNone,
+    /// Jump at both ends
+    JumpBoth,
+}
+
+/// CSS cubic Bézier timing curve from (0, 0) to (1, 1)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl CubicBezier {
+    /// Creates the curve; `x1` and `x2` are clamped to 0.0-1.0 so it stays a function of time
+    pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// Eased progress at linear progress `x` (0.0-1.0)
+    pub fn apply(&self, x: f32) -> f32 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+        let t = self.solve_x(f64::from(x));
+        sample(f64::from(self.y1), f64::from(self.y2), t) as f32
+    }
+
+    // Curve parameter whose x is `x`: Newton's method, falling back to bisection
+    fn solve_x(&self, x: f64) -> f64 {
+        const EPSILON: f64 = 1e-7;
+        let (x1, x2) = (f64::from(self.x1), f64::from(self.x2));
+
+        let mut t = x;
+        for _ in 0..8 {
+            let error = sample(x1, x2, t) - x;
+            if error.abs() < EPSILON {
+                return t;
+            }
+            let slope = sample_derivative(x1, x2, t);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            t -= error / slope;
+        }
+
+        let (mut low, mut high) = (0.0, 1.0);
+        t = x;
+        for _ in 0..64 {
+            let value = sample(x1, x2, t);
+            if (value - x).abs() < EPSILON {
+                break;
+            }
+            if value < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) * 0.5;
+        }
+        t
+    }
+}
+
+// One coordinate of the Bézier curve with control points 0, p1, p2, 1 at parameter t
+fn sample(p1: f64, p2: f64, t: f64) -> f64 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    ((a * t + b) * t + c) * t
+}
+
+fn sample_derivative(p1: f64, p2: f64, t: f64) -> f64 {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    (3.0 * a * t + 2.0 * b) * t + c
+}
+
+/// Error returned when a CSS easing string can't be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseEasingError(String);
+
+impl fmt::Display for ParseEasingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid CSS easing `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseEasingError {}
+
+/// Parses CSS easing syntax: keywords, `cubic-bezier()`, `steps()` and `linear()`
+impl FromStr for Easing {
+    type Err = ParseEasingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let easing = CssEasing::parse(s).ok_or_else(|| ParseEasingError(s.to_string()))?;
+        Ok(match easing {
+            CssEasing::Linear => Self::default(),
+            CssEasing::Ease => Self::ease(),
+            CssEasing::EaseIn => Self::ease_in(),
+            CssEasing::EaseOut => Self::ease_out(),
+            CssEasing::EaseInOut => Self::ease_in_out(),
+            CssEasing::CubicBezier(x1, y1, x2, y2) => Self::cubic_bezier(x1, y1, x2, y2),
+            CssEasing::Steps(count, jump) => Self::steps(
+                count,
+                match jump {
+                    StepJump::Start => StepPosition::JumpStart,
+                    StepJump::End => StepPosition::JumpEnd,
+                    StepJump::None => StepPosition::JumpNone,
+                    StepJump::Both => StepPosition::JumpBoth,
+                },
+            ),
+            CssEasing::LinearStops(stops) => Self::linear_stops(stops),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use instant::Duration;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_cubic_bezier() {
+        let linear = Easing::cubic_bezier(0.0, 0.0, 1.0, 1.0);
+        assert_close(linear.apply(0.3), 0.3);
+
+        // Reference values of CSS `ease`
+        let ease = Easing::ease();
+        assert_close(ease.apply(0.0), 0.0);
+        assert_close(ease.apply(0.25), 0.40851);
+        assert_close(ease.apply(0.5), 0.80240);
+        assert_close(ease.apply(1.0), 1.0);
+
+        // Overshooting curves go past 1.0
+        let back = Easing::cubic_bezier(0.34, 1.56, 0.64, 1.0);
+        assert!(back.apply(0.6) > 1.0);
+    }
+
+    #[test]
+    fn test_steps() {
+        let end = Easing::steps(4, StepPosition::JumpEnd);
+        assert_eq!(end.apply(0.0), 0.0);
+        assert_eq!(end.apply(0.3), 0.25);
+        assert_eq!(end.apply(1.0), 1.0);
+
+        let start = Easing::steps(4, StepPosition::JumpStart);
+        assert_eq!(start.apply(0.0), 0.25);
+        assert_eq!(start.apply(0.3), 0.5);
+
+        let none = Easing::steps(3, StepPosition::JumpNone);
+        assert_eq!(none.apply(0.0), 0.0);
+        assert_eq!(none.apply(0.5), 0.5);
+        assert_eq!(none.apply(1.0), 1.0);
+
+        let both = Easing::steps(3, StepPosition::JumpBoth);
+        assert_eq!(both.apply(0.0), 0.25);
+        assert_eq!(both.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_linear_stops() {
+        let even = Easing::linear([0.0, 0.5, 1.0]);
+        assert_close(even.apply(0.25), 0.25);
+
+        // linear(0, 0.25 75%, 1)
+        let custom = Easing::linear_stops([(0.0, None), (0.25, Some(0.75)), (1.0, None)]);
+        assert_close(custom.apply(0.375), 0.125);
+        assert_close(custom.apply(0.875), 0.625);
+
+        // Missing inputs are spread between known ones, inputs never go backwards
+        let Easing::Linear(points) = Easing::linear_stops([
+            (0.0, None),
+            (0.2, None),
+            (0.4, None),
+            (0.6, Some(0.9)),
+            (1.0, Some(0.5)),
+        ]) else {
+            unreachable!()
+        };
+        for (point, input) in points.iter().zip([0.0, 0.3, 0.6, 0.9, 0.9]) {
+            assert_close(point.0, input);
+        }
+    }
+
+    #[test]
+    fn test_spring_easing() {
+        let wobbly = Easing::spring(Spring::wobbly());
+        assert_eq!(wobbly.apply(0.0), 0.0);
+        assert_eq!(wobbly.apply(1.0), 1.0);
+        // Overshoots like the spring it was sampled from
+        let peak = (0..=100)
+            .map(|i| wobbly.apply(i as f32 / 100.0))
+            .fold(0.0, f32::max);
+        assert!(peak > 1.05);
+
+        // Follows the spring at the same point in time
+        let spring = Spring::default();
+        let eased = Easing::spring_with_samples(spring, 200);
+        let time = 0.25 * spring.settling_time().as_secs_f32();
+        assert_close(eased.apply(0.25), 1.0 - spring.response(time).position.0);
+
+        let critical = Easing::spring(Spring::critically_damped(Duration::from_millis(300)));
+        let mut previous = 0.0;
+        for i in 0..=50 {
+            let value = critical.apply(i as f32 / 50.0);
+            assert!(value >= previous - 1e-6);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn test_to_css() {
+        assert_eq!(
+            Easing::ease().to_css().as_deref(),
+            Some("cubic-bezier(0.25, 0.1, 0.25, 1)")
+        );
+        assert_eq!(
+            Easing::steps(3, StepPosition::JumpStart)
+                .to_css()
+                .as_deref(),
+            Some("steps(3, jump-start)")
+        );
+        assert_eq!(
+            Easing::linear([0.0, 0.25, 1.0]).to_css().as_deref(),
+            Some("linear(0 0%, 0.25 50%, 1 100%)")
+        );
+        assert_eq!(Easing::Function(|t, _, _, _| t).to_css(), None);
+
+        // Exported springs parse back into the same curve
+        let spring = Easing::spring(Spring::gentle());
+        let css = spring.to_css().unwrap_or_default();
+        let parsed: Easing = css.parse().unwrap_or_default();
+        for i in 0..=20 {
+            let t = i as f32 / 20.0;
+            assert!((spring.apply(t) - parsed.apply(t)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_parse_css() {
+        assert_eq!("ease-in-out".parse::<Easing>(), Ok(Easing::ease_in_out()));
+        assert_eq!(
+            "cubic-bezier(0.4, 0, 0.2, 1)".parse::<Easing>(),
+            Ok(Easing::cubic_bezier(0.4, 0.0, 0.2, 1.0))
+        );
+        assert_eq!(
+            "steps(4, jump-start)".parse::<Easing>(),
+            Ok(Easing::steps(4, StepPosition::JumpStart))
+        );
+        assert_eq!(
+            "linear(0, 0.25 75%, 1)".parse::<Easing>(),
+            Ok(Easing::linear_stops([
+                (0.0, None),
+                (0.25, Some(0.75)),
+                (1.0, None)
+            ]))
+        );
+        assert_eq!(
+            "linear(0, 0.5 25% 75%, 1)".parse::<Easing>(),
+            Ok(Easing::linear_stops([
+                (0.0, None),
+                (0.5, Some(0.25)),
+                (0.5, Some(0.75)),
+                (1.0, None)
+            ]))
+        );
+        assert!("cubic-bezier(1, 2)".parse::<Easing>().is_err());
+        assert!("steps(0)".parse::<Easing>().is_err());
+        assert!("bounce".parse::<Easing>().is_err());
+    }
+}
@@ -0,0 +1,228 @@
+//! Keyframe animation module
+//!
+//! Provides a keyframe track: values placed at normalized offsets along a single
+//! duration, each segment with its own easing. A track plays like one tween, so
+//! progress, looping and completion callbacks cover the whole track.
+
+use easer::functions::{Easing as _, Linear};
+use instant::Duration;
+use smallvec::SmallVec;
+
+use crate::animations::{easing::Easing, utils::Animatable};
+
+/// A single value on a keyframe track
+#[derive(Debug, Clone)]
+pub struct Keyframe<T: Animatable> {
+    /// Value reached at this keyframe
+    pub value: T,
+    /// Normalized position on the track (0.0-1.0)
+    pub offset: f32,
+    /// Easing used for the segment leading into this keyframe
+    pub easing: Easing,
+}
+
+/// A keyframe track with one overall duration
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// use easer::functions::Easing as _;
+///
+/// let bounce = Keyframes::new(Duration::from_millis(600))
+///     .at(0.0, 1.0f32)
+///     .at_with_easing(0.4, 1.3, easer::functions::Cubic::ease_out)
+///     .at_with_timing(1.0, 1.0, Easing::ease_in_out());
+/// assert_eq!(bounce.value_at(0.4), Some(1.3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keyframes<T: Animatable> {
+    frames: SmallVec<[Keyframe<T>; 8]>,
+    duration: Duration,
+}
+
+impl<T: Animatable> Keyframes<T> {
+    /// Creates an empty track playing over `duration`
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            frames: SmallVec::new(),
+            duration,
+        }
+    }
+
+    /// Places `value` at `offset` with linear easing into it
+    pub fn at(self, offset: f32, value: T) -> Self {
+        self.at_with_easing(offset, value, Linear::ease_in_out)
+    }
+
+    /// Places `value` at `offset`, easing the segment from the previous keyframe
+    ///
+    /// Offsets are clamped to 0.0-1.0 and keyframes are kept sorted by offset.
+    pub fn at_with_easing(
+        self,
+        offset: f32,
+        value: T,
+        easing: fn(f32, f32, f32, f32) -> f32,
+    ) -> Self {
+        self.at_with_timing(offset, value, Easing::Function(easing))
+    }
+
+    /// Places `value` at `offset` with a CSS-style timing curve into it
+    pub fn at_with_timing(mut self, offset: f32, value: T, easing: Easing) -> Self {
+        let offset = offset.clamp(0.0, 1.0);
+        // Insert after any keyframe sharing the same offset to keep insertion order stable
+        let index = self.frames.partition_point(|frame| frame.offset <= offset);
+        self.frames.insert(
+            index,
+            Keyframe {
+                value,
+                offset,
+                easing,
+            },
+        );
+        self
+    }
+
+    /// Total duration of the track
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Keyframes sorted by offset
+    pub fn frames(&self) -> &[Keyframe<T>] {
+        &self.frames
+    }
+
+    /// Samples the track at `progress` (0.0-1.0)
+    ///
+    /// Before the first keyframe the first value is held, after the last keyframe
+    /// the last value is held. Returns `None` for an empty track.
+    pub fn value_at(&self, progress: f32) -> Option<T> {
+        let first = self.frames.first()?;
+        let last = self.frames.last()?;
+
+        if progress <= first.offset {
+            return Some(first.value);
+        }
+        if progress >= last.offset {
+            return Some(last.value);
+        }
+
+        let index = self
+            .frames
+            .partition_point(|frame| frame.offset <= progress)
+            .max(1);
+        let from = &self.frames[index - 1];
+        let to = &self.frames[index];
+
+        let span = to.offset - from.offset;
+        let local = if span <= 0.0 {
+            1.0
+        } else {
+            (progress - from.offset) / span
+        };
+        let eased = to.easing.apply(local);
+
+        Some(from.value.interpolate(&to.value, eased))
+    }
+
+    /// Makes sure the track starts at 0.0 and ends at 1.0
+    ///
+    /// Missing ends are filled with `from` at the start and the last value at the end.
+    pub(crate) fn normalized(mut self, from: T) -> Self {
+        if self.frames.first().is_none_or(|frame| frame.offset > 0.0) {
+            self.frames.insert(
+                0,
+                Keyframe {
+                    value: from,
+                    offset: 0.0,
+                    easing: Easing::Function(Linear::ease_in_out),
+                },
+            );
+        }
+        if let Some(last) = self.frames.last().cloned() {
+            if last.offset < 1.0 {
+                self.frames.push(Keyframe {
+                    offset: 1.0,
+                    easing: Easing::Function(Linear::ease_in_out),
+                    ..last
+                });
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{AnimationConfig, LoopMode};
+    use crate::Motion;
+    use easer::functions::Cubic;
+
+    fn track() -> Keyframes<f32> {
+        Keyframes::new(Duration::from_secs(1))
+            .at(1.0, 0.0)
+            .at(0.0, 0.0)
+            .at_with_easing(0.5, 100.0, Cubic::ease_out)
+    }
+
+    #[test]
+    fn test_keyframes_sorted() {
+        let offsets: Vec<f32> = track().frames().iter().map(|f| f.offset).collect();
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_keyframes_value_at() {
+        let keyframes = track();
+        assert_eq!(keyframes.value_at(0.0), Some(0.0));
+        assert_eq!(keyframes.value_at(0.5), Some(100.0));
+        assert_eq!(keyframes.value_at(1.0), Some(0.0));
+        // Second segment is linear
+        assert_eq!(keyframes.value_at(0.75), Some(50.0));
+        // First segment uses its own easing
+        let eased = Cubic::ease_out(0.5, 0.0, 100.0, 1.0);
+        assert!((keyframes.value_at(0.25).unwrap_or_default() - eased).abs() < 1e-4);
+        assert_eq!(Keyframes::<f32>::new(Duration::ZERO).value_at(0.5), None);
+    }
+
+    #[test]
+    fn test_keyframes_normalized() {
+        let keyframes = Keyframes::new(Duration::from_secs(1))
+            .at(0.5, 10.0f32)
+            .normalized(4.0);
+        assert_eq!(keyframes.value_at(0.0), Some(4.0));
+        assert_eq!(keyframes.frames().len(), 3);
+        assert_eq!(keyframes.value_at(1.0), Some(10.0));
+    }
+
+    #[test]
+    fn test_motion_plays_keyframes() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(track(), AnimationConfig::default());
+
+        motion.update(0.5);
+        assert_eq!(motion.value(), 100.0);
+        assert!((motion.progress() - 0.5).abs() < 1e-6);
+
+        motion.update(0.5);
+        assert_eq!(motion.value(), 0.0);
+        assert!(!motion.is_running());
+    }
+
+    #[test]
+    fn test_motion_loops_whole_track() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_keyframes(
+            track(),
+            AnimationConfig::default().with_loop(LoopMode::Times(2)),
+        );
+
+        motion.update(1.0);
+        assert!(motion.is_running());
+        motion.update(0.5);
+        assert_eq!(motion.value(), 100.0);
+        motion.update(0.5);
+        assert!(!motion.is_running());
+    }
+}
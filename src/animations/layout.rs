@@ -0,0 +1,542 @@
+//! Layout animation module
+//!
+//! FLIP (First, Last, Invert, Play) animations for elements that move or resize.
+//! The element's bounds are measured after each render; when they change, it is
+//! transformed back onto its previous bounds and animated into its new layout.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dioxus::html::geometry::PixelsRect;
+use dioxus::prelude::*;
+
+use crate::animations::spring::Spring;
+use crate::animations::utils::{Animatable, AnimationConfig, AnimationMode};
+use crate::{use_motion, AnimationManager};
+
+/// Changes smaller than this many pixels are ignored
+const LAYOUT_TOLERANCE: f32 = 0.5;
+
+/// Identifies elements taking part in shared layout animations
+static NEXT_OWNER: AtomicUsize = AtomicUsize::new(0);
+
+/// Bounds of an element in client (viewport) pixels
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LayoutRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl LayoutRect {
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Bounds after applying `transform` with a top-left origin
+    pub fn transformed(&self, transform: LayoutTransform) -> Self {
+        Self::new(
+            self.x + transform.x,
+            self.y + transform.y,
+            self.width * transform.scale_x,
+            self.height * transform.scale_y,
+        )
+    }
+
+    /// Bounds before `transform` was applied, the inverse of [`LayoutRect::transformed`]
+    pub fn untransformed(&self, transform: LayoutTransform) -> Self {
+        let unscale = |size: f32, scale: f32| if scale == 0.0 { size } else { size / scale };
+        Self::new(
+            self.x - transform.x,
+            self.y - transform.y,
+            unscale(self.width, transform.scale_x),
+            unscale(self.height, transform.scale_y),
+        )
+    }
+
+    /// Whether both bounds differ by less than half a pixel
+    pub fn approx_eq(&self, other: &Self) -> bool {
+        (self.x - other.x).abs() < LAYOUT_TOLERANCE
+            && (self.y - other.y).abs() < LAYOUT_TOLERANCE
+            && (self.width - other.width).abs() < LAYOUT_TOLERANCE
+            && (self.height - other.height).abs() < LAYOUT_TOLERANCE
+    }
+}
+
+impl From<PixelsRect> for LayoutRect {
+    fn from(rect: PixelsRect) -> Self {
+        Self::new(
+            rect.origin.x as f32,
+            rect.origin.y as f32,
+            rect.size.width as f32,
+            rect.size.height as f32,
+        )
+    }
+}
+
+/// Translation and per-axis scale applied while an element animates into its layout
+///
+/// Unlike [`Transform`](crate::prelude::Transform) it scales each axis separately,
+/// so it can map any box onto any other.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutTransform {
+    pub x: f32,
+    pub y: f32,
+    pub scale_x: f32,
+    pub scale_y: f32,
+}
+
+impl LayoutTransform {
+    pub fn new(x: f32, y: f32, scale_x: f32, scale_y: f32) -> Self {
+        Self {
+            x,
+            y,
+            scale_x,
+            scale_y,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(0.0, 0.0, 1.0, 1.0)
+    }
+
+    /// Transform drawing the box laid out at `to` onto `from`
+    pub fn between(from: LayoutRect, to: LayoutRect) -> Self {
+        let ratio = |from: f32, to: f32| if to == 0.0 { 1.0 } else { from / to };
+        Self::new(
+            from.x - to.x,
+            from.y - to.y,
+            ratio(from.width, to.width),
+            ratio(from.height, to.height),
+        )
+    }
+
+    /// CSS `transform` value, to be used with a top-left `transform-origin`
+    pub fn to_css(&self) -> String {
+        format!(
+            "translate({}px, {}px) scale({}, {})",
+            self.x, self.y, self.scale_x, self.scale_y
+        )
+    }
+
+    /// Inverse scale for children, keeping them undistorted while the parent scales
+    pub fn child_correction(&self) -> Self {
+        let invert = |scale: f32| if scale == 0.0 { 1.0 } else { 1.0 / scale };
+        Self::new(0.0, 0.0, invert(self.scale_x), invert(self.scale_y))
+    }
+}
+
+impl Default for LayoutTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Animatable for LayoutTransform {
+    fn zero() -> Self {
+        Self::new(0.0, 0.0, 0.0, 0.0)
+    }
+
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    fn magnitude(&self) -> f32 {
+        (self.x * self.x
+            + self.y * self.y
+            + self.scale_x * self.scale_x
+            + self.scale_y * self.scale_y)
+            .sqrt()
+    }
+
+    fn scale(&self, factor: f32) -> Self {
+        Self::new(
+            self.x * factor,
+            self.y * factor,
+            self.scale_x * factor,
+            self.scale_y * factor,
+        )
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self::new(
+            self.x + other.x,
+            self.y + other.y,
+            self.scale_x + other.scale_x,
+            self.scale_y + other.scale_y,
+        )
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Self::new(
+            self.x - other.x,
+            self.y - other.y,
+            self.scale_x - other.scale_x,
+            self.scale_y - other.scale_y,
+        )
+    }
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        self.add(&target.sub(self).scale(t))
+    }
+}
+
+/// Last known bounds of an element tagged with a `layout_id`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SharedEntry {
+    rect: LayoutRect,
+    owner: usize,
+}
+
+/// Entry to store after measuring `rect`, and the bounds handed over by another element
+///
+/// An element measured for the first time takes over its `layout_id`, animating from
+/// the bounds of the previous owner. Elements that were taken over stop publishing.
+fn publish(
+    entry: Option<SharedEntry>,
+    owner: usize,
+    rect: LayoutRect,
+    first: bool,
+) -> (Option<SharedEntry>, Option<LayoutRect>) {
+    let update = Some(SharedEntry { rect, owner });
+    match entry {
+        None => (update, None),
+        Some(entry) if entry.owner != owner && first => (update, Some(entry.rect)),
+        Some(entry) if entry.owner != owner => (None, None),
+        Some(entry) if entry.rect.approx_eq(&rect) => (None, None),
+        Some(_) => (update, None),
+    }
+}
+
+/// Membership of an element in the app-wide registry of shared layouts
+#[derive(Clone, Copy)]
+struct SharedLayout {
+    entries: Signal<HashMap<String, SharedEntry>>,
+    layout_id: CopyValue<String>,
+    owner: usize,
+}
+
+impl SharedLayout {
+    fn use_shared(layout_id: Option<String>) -> Option<Self> {
+        let shared = use_hook(|| {
+            let layout_id = layout_id?;
+            let entries = try_consume_context().unwrap_or_else(|| {
+                provide_root_context(Signal::new_in_scope(HashMap::new(), ScopeId::ROOT))
+            });
+            Some(Self {
+                entries,
+                layout_id: CopyValue::new(layout_id),
+                owner: NEXT_OWNER.fetch_add(1, Ordering::Relaxed),
+            })
+        });
+        use_drop(move || {
+            if let Some(shared) = shared {
+                shared.release();
+            }
+        });
+        shared
+    }
+
+    /// Whether no other element has taken over the `layout_id`
+    fn is_owner(&self) -> bool {
+        self.entries
+            .read()
+            .get(&*self.layout_id.read())
+            .is_none_or(|entry| entry.owner == self.owner)
+    }
+
+    /// Records `rect`, returning the bounds to animate from when taking over
+    fn publish(&mut self, rect: LayoutRect, first: bool) -> Option<LayoutRect> {
+        let layout_id = self.layout_id.read().clone();
+        let entry = self.entries.peek().get(&layout_id).copied();
+        let (update, handoff) = publish(entry, self.owner, rect, first);
+        if let Some(update) = update {
+            self.entries.write().insert(layout_id, update);
+        }
+        handoff
+    }
+
+    fn release(mut self) {
+        let layout_id = self.layout_id.read().clone();
+        let owned = self
+            .entries
+            .peek()
+            .get(&layout_id)
+            .is_some_and(|entry| entry.owner == self.owner);
+        if owned {
+            self.entries.write().remove(&layout_id);
+        }
+    }
+}
+
+/// Layout animation of one element, returned by [`use_layout_animation`]
+#[derive(Clone, Copy)]
+pub struct LayoutAnimation<M: AnimationManager<LayoutTransform>> {
+    motion: M,
+    config: Signal<AnimationConfig>,
+    node: Signal<Option<Rc<MountedData>>>,
+    layout: Signal<Option<LayoutRect>>,
+    shared: Option<SharedLayout>,
+}
+
+impl<M: AnimationManager<LayoutTransform>> LayoutAnimation<M> {
+    /// Tracks the element, attach to its `onmounted`
+    pub fn on_mounted(&mut self, event: MountedEvent) {
+        self.node.set(Some(event.data()));
+    }
+
+    pub fn value(&self) -> LayoutTransform {
+        self.motion.get_value()
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.motion.is_running()
+    }
+
+    /// Whether another element with the same `layout_id` has taken over
+    pub fn is_handed_off(&self) -> bool {
+        self.shared.is_some_and(|shared| !shared.is_owner())
+    }
+
+    /// Inline style for the animated element, hiding it once handed off
+    pub fn style(&self) -> String {
+        let visibility = if self.is_handed_off() {
+            " visibility: hidden;"
+        } else {
+            ""
+        };
+        format!(
+            "transform: {}; transform-origin: 0 0;{visibility}",
+            self.value().to_css()
+        )
+    }
+
+    /// Inline style for a wrapper around the element's children, undoing its scale
+    pub fn child_style(&self) -> String {
+        format!(
+            "transform: {}; transform-origin: 0 0;",
+            self.value().child_correction().to_css()
+        )
+    }
+
+    /// Measures the element and animates it from its previous bounds if they changed
+    ///
+    /// `rendered` is the transform applied when the element was measured.
+    async fn measure(mut self, rendered: LayoutTransform) {
+        let Some(node) = self.node.peek().clone() else {
+            return;
+        };
+        let Ok(rect) = node.get_client_rect().await else {
+            return;
+        };
+        let layout = LayoutRect::from(rect).untransformed(rendered);
+        let previous = self.layout.write().replace(layout);
+        let handoff = self
+            .shared
+            .as_mut()
+            .and_then(|shared| shared.publish(layout, previous.is_none()));
+
+        // Invert: draw the new layout where the element currently appears, then play back
+        let appears = match (previous, handoff) {
+            (_, Some(handoff)) => handoff,
+            (Some(previous), None) if !previous.approx_eq(&layout) => {
+                previous.transformed(self.motion.get_value())
+            }
+            _ => return,
+        };
+        self.motion
+            .set_value(LayoutTransform::between(appears, layout));
+        self.motion
+            .animate_to(LayoutTransform::identity(), self.config.peek().clone());
+    }
+}
+
+/// Animates an element between layouts when its position or size changes
+///
+/// The element is measured with `MountedData::get_client_rect` after every render
+/// of the calling component, so changes caused elsewhere are picked up on its next
+/// render. Apply [`LayoutAnimation::style`] to the element and, to keep its content
+/// from stretching, [`LayoutAnimation::child_style`] to a wrapper inside it.
+///
+/// Each measurement is a layout read (`getBoundingClientRect` on web). The animated
+/// style re-renders the component on every frame of an animation, so it's measured
+/// every frame while it moves, which lets it follow layout changes mid-flight. Keep
+/// the component small so those renders stay cheap.
+///
+/// # Examples
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// #[component]
+/// fn Item(label: String) -> Element {
+///     let mut layout = use_layout_animation(AnimationConfig::new(AnimationMode::Spring(
+///         Spring::default(),
+///     )));
+///
+///     rsx! {
+///         li {
+///             style: layout.style(),
+///             onmounted: move |e| layout.on_mounted(e),
+///             div { style: layout.child_style(), "{label}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_layout_animation(
+    config: AnimationConfig,
+) -> LayoutAnimation<impl AnimationManager<LayoutTransform>> {
+    use_layout(config, None)
+}
+
+/// Layout animation shared by every element tagged with `layout_id`
+///
+/// When an element mounts while another one with the same `layout_id` is on screen,
+/// e.g. on the old and the new route during an [`AnimatedOutlet`] transition, it
+/// morphs from the other element's position and size into its own, and the other
+/// element is hidden. `layout_id` is read on the first render.
+///
+/// [`AnimatedOutlet`]: crate::transitions::page_transitions::AnimatedOutlet
+///
+/// # Examples
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// #[component]
+/// fn Thumbnail(id: usize) -> Element {
+///     let mut hero = use_shared_layout(
+///         format!("photo-{id}"),
+///         AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+///     );
+///
+///     rsx! {
+///         img {
+///             style: hero.style(),
+///             onmounted: move |e| hero.on_mounted(e),
+///             src: "/photos/{id}.jpg",
+///         }
+///     }
+/// }
+/// ```
+pub fn use_shared_layout(
+    layout_id: impl Into<String>,
+    config: AnimationConfig,
+) -> LayoutAnimation<impl AnimationManager<LayoutTransform>> {
+    use_layout(config, Some(layout_id.into()))
+}
+
+fn use_layout(
+    config: AnimationConfig,
+    layout_id: Option<String>,
+) -> LayoutAnimation<impl AnimationManager<LayoutTransform>> {
+    let animation = LayoutAnimation {
+        motion: use_motion(LayoutTransform::identity()),
+        config: use_signal(|| config),
+        node: use_signal(|| None),
+        layout: use_signal(|| None),
+        shared: SharedLayout::use_shared(layout_id),
+    };
+
+    // Runs after this render has been applied, i.e. on the new layout. Not gated on
+    // anything since any render may have moved the element
+    let rendered = animation.value();
+    queue_effect(move || {
+        spawn(animation.measure(rendered));
+    });
+
+    animation
+}
+
+/// Wraps its children in an element that animates layout changes, see [`use_layout_animation`]
+///
+/// With a `layout_id` it morphs from other elements with the same id, see [`use_shared_layout`].
+#[component]
+pub fn LayoutMotion(
+    children: Element,
+    /// Animation used to move into the new layout (default: spring)
+    #[props(default = AnimationMode::Spring(Spring::default()))]
+    mode: AnimationMode,
+    /// Shares the layout with other elements tagged with the same id
+    #[props(default, into)]
+    layout_id: Option<String>,
+    #[props(default)] class: String,
+) -> Element {
+    let mut layout = use_layout(AnimationConfig::new(mode), layout_id);
+
+    rsx! {
+        div {
+            class,
+            style: layout.style(),
+            onmounted: move |e| layout.on_mounted(e),
+            div { style: layout.child_style(), {children} }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_transform_between() {
+        let from = LayoutRect::new(0.0, 0.0, 100.0, 50.0);
+        let to = LayoutRect::new(200.0, 40.0, 200.0, 100.0);
+        let invert = LayoutTransform::between(from, to);
+        assert_eq!(invert, LayoutTransform::new(-200.0, -40.0, 0.5, 0.5));
+        // Inverting draws the new layout exactly over the old one
+        assert_eq!(to.transformed(invert), from);
+        assert_eq!(from.untransformed(invert), to);
+    }
+
+    #[test]
+    fn test_layout_child_correction() {
+        let transform = LayoutTransform::new(10.0, 0.0, 2.0, 0.5);
+        let child = transform.child_correction();
+        assert_eq!(child, LayoutTransform::new(0.0, 0.0, 0.5, 2.0));
+        assert_eq!(
+            transform.to_css(),
+            "translate(10px, 0px) scale(2, 0.5)".to_string()
+        );
+    }
+
+    #[test]
+    fn test_layout_plays_back_to_identity() {
+        let mut motion = crate::Motion::new(LayoutTransform::new(-200.0, 0.0, 0.5, 1.0));
+        motion.animate_to(
+            LayoutTransform::identity(),
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        for _ in 0..600 {
+            motion.update(1.0 / 60.0);
+        }
+        assert_eq!(motion.value(), LayoutTransform::identity());
+    }
+
+    #[test]
+    fn test_shared_layout_handoff() {
+        let thumbnail = LayoutRect::new(10.0, 10.0, 50.0, 50.0);
+        let header = LayoutRect::new(0.0, 0.0, 400.0, 200.0);
+
+        let (entry, handoff) = publish(None, 0, thumbnail, true);
+        assert_eq!(handoff, None);
+        let entry = entry.expect("first element publishes its bounds");
+
+        // The element on the new route takes over from the thumbnail
+        let (update, handoff) = publish(Some(entry), 1, header, true);
+        assert_eq!(handoff, Some(thumbnail));
+        let entry = update.expect("new element owns the layout id");
+        assert_eq!(entry.owner, 1);
+
+        // The thumbnail no longer publishes, and unchanged bounds aren't rewritten
+        assert_eq!(publish(Some(entry), 0, thumbnail, false), (None, None));
+        assert_eq!(publish(Some(entry), 1, header, false), (None, None));
+    }
+}
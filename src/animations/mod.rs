@@ -1,6 +1,14 @@
 pub mod colors;
+pub mod decay;
+pub(crate) mod driver;
+pub mod easing;
+pub mod keyframes;
+pub mod layout;
 pub mod platform;
+pub mod settings;
 pub mod spring;
+pub mod stagger;
+pub mod timeline;
 pub mod transform;
 pub mod tween;
 pub mod utils;
@@ -2,20 +2,52 @@
 //!
 //! Provides cross-platform timing operations for animations.
 //! Supports both web (WASM) and native platforms.
+//!
+//! Motions read the time from the [Clock] found in the context, falling back to
+//! [MotionTime]. Provide another one to slow down, freeze or sync every animation below:
+//!
+//! ```rust,no_run
+//! use dioxus::prelude::*;
+//! use dioxus_motion::prelude::*;
+//!
+//! #[derive(Clone)]
+//! struct VideoClock;
+//!
+//! impl TimeProvider for VideoClock {
+//!     fn now(&self) -> instant::Instant {
+//!         // e.g. derived from the current time of a video element
+//!         instant::Instant::now()
+//!     }
+//!
+//!     async fn delay(&self, duration: Duration) {
+//!         MotionTime.delay(duration).await
+//!     }
+//! }
+//!
+//! fn app() -> Element {
+//!     use_context_provider(|| Clock::new(VideoClock));
+//!     rsx! {}
+//! }
+//! ```
 
+use dioxus::prelude::*;
 use instant::{Duration, Instant};
+
+use crate::animations::driver::FrameDriver;
 use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
 
 /// Provides platform-agnostic timing operations
 ///
 /// Abstracts timing functionality across different platforms,
 /// ensuring consistent animation behavior in both web and native environments.
-pub trait TimeProvider {
+pub trait TimeProvider: Clone + 'static {
     /// Returns the current instant
-    fn now() -> Instant;
+    fn now(&self) -> Instant;
 
     /// Creates a future that completes after the specified duration
-    fn delay(duration: Duration) -> impl Future<Output = ()>;
+    fn delay(&self, duration: Duration) -> impl Future<Output = ()>;
 }
 
 /// Default time provider implementation for motion animations
@@ -27,7 +59,7 @@ pub trait TimeProvider {
 pub struct MotionTime;
 
 impl TimeProvider for MotionTime {
-    fn now() -> Instant {
+    fn now(&self) -> Instant {
         Instant::now()
     }
 
@@ -40,7 +72,7 @@ impl TimeProvider for MotionTime {
     /// # Native
     /// Uses tokio::time::sleep
     #[cfg(feature = "web")]
-    fn delay(_duration: Duration) -> impl Future<Output = ()> {
+    fn delay(&self, _duration: Duration) -> impl Future<Output = ()> {
         use futures_util::FutureExt;
         use wasm_bindgen::prelude::*;
         use web_sys::window;
@@ -79,7 +111,7 @@ impl TimeProvider for MotionTime {
     }
 
     #[cfg(not(feature = "web"))]
-    fn delay(duration: Duration) -> impl Future<Output = ()> {
+    fn delay(&self, duration: Duration) -> impl Future<Output = ()> {
         Box::pin(async move {
             let start = std::time::Instant::now();
 
@@ -96,3 +128,73 @@ impl TimeProvider for MotionTime {
 
 /// Type alias for the default time provider
 pub type Time = MotionTime;
+
+/// Object-safe [TimeProvider], behind [Clock]
+trait DynTimeProvider {
+    fn now(&self) -> Instant;
+    fn delay(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + '_>>;
+}
+
+impl<P: TimeProvider> DynTimeProvider for P {
+    fn now(&self) -> Instant {
+        TimeProvider::now(self)
+    }
+
+    fn delay(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + '_>> {
+        Box::pin(TimeProvider::delay(self, duration))
+    }
+}
+
+/// Any [TimeProvider], shared through the context to drive every motion below it
+///
+/// Defaults to [MotionTime]. Every motion using the same clock is ticked by a single
+/// frame loop, which sleeps while none of them is running.
+#[derive(Clone)]
+pub struct Clock {
+    provider: Rc<dyn DynTimeProvider>,
+    driver: FrameDriver,
+}
+
+impl Clock {
+    pub fn new(provider: impl TimeProvider) -> Self {
+        Self {
+            provider: Rc::new(provider),
+            driver: FrameDriver::default(),
+        }
+    }
+
+    pub(crate) fn driver(&self) -> FrameDriver {
+        self.driver.clone()
+    }
+
+    /// Same time provider without the frame driver, which must not keep itself alive
+    pub(crate) fn detached(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            driver: FrameDriver::default(),
+        }
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new(MotionTime)
+    }
+}
+
+impl TimeProvider for Clock {
+    fn now(&self) -> Instant {
+        self.provider.now()
+    }
+
+    fn delay(&self, duration: Duration) -> impl Future<Output = ()> {
+        self.provider.delay(duration)
+    }
+}
+
+/// The [Clock] provided by the closest context, or the global default one
+pub fn use_clock() -> Clock {
+    use_hook(|| {
+        try_consume_context::<Clock>().unwrap_or_else(|| provide_root_context(Clock::default()))
+    })
+}
@@ -0,0 +1,203 @@
+//! Settings module
+//!
+//! [MotionSettings] slow down, freeze or step every motion at once, e.g. to inspect a
+//! transition at 10% speed. The settings live in a signal shared through the context:
+//! a global one by default, or one provided to a subtree with
+//! [use_motion_settings_provider].
+//!
+//! They also hold the [ReducedMotion] policy. When motion is reduced, animations jump
+//! straight to their end and route transitions become a short cross-fade.
+
+use dioxus::prelude::*;
+
+/// Duration of a frame played by [MotionSettings::step] while paused
+const STEP_SECS: f32 = 1.0 / 60.0;
+
+/// Whether animations are reduced for accessibility
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReducedMotion {
+    /// Always reduce motion
+    Always,
+    /// Never reduce motion, e.g. for animations conveying essential information
+    Never,
+    /// Reduce motion when the user asks for it (`prefers-reduced-motion` on web)
+    #[default]
+    User,
+}
+
+/// Reads `prefers-reduced-motion` from the browser
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn detect_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media("(prefers-reduced-motion: reduce)").ok())
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
+
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn detect_reduced_motion() -> bool {
+    false
+}
+
+/// Playback settings applied by every motion when it computes its frame time
+///
+/// # Examples
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn app() -> Element {
+///     let mut settings = use_motion_settings();
+///
+///     rsx! {
+///         button { onclick: move |_| settings.write().time_scale = 0.1, "Slow motion" }
+///         button { onclick: move |_| settings.write().paused ^= true, "Pause" }
+///         button { onclick: move |_| settings.write().step(), "Next frame" }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionSettings {
+    /// Speed of every animation, e.g. `0.1` for 10%
+    pub time_scale: f32,
+    /// Freezes every animation until resumed or stepped
+    pub paused: bool,
+    /// Policy of animations that don't set their own
+    pub reduced_motion: ReducedMotion,
+    /// Whether the user asked for reduced motion, detected on web and set by the app
+    /// elsewhere
+    pub prefers_reduced_motion: bool,
+    steps: u64,
+}
+
+impl Default for MotionSettings {
+    fn default() -> Self {
+        Self {
+            time_scale: 1.0,
+            paused: false,
+            reduced_motion: ReducedMotion::default(),
+            prefers_reduced_motion: detect_reduced_motion(),
+            steps: 0,
+        }
+    }
+}
+
+impl MotionSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    pub fn with_paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    pub fn with_reduced_motion(mut self, policy: ReducedMotion) -> Self {
+        self.reduced_motion = policy;
+        self
+    }
+
+    pub fn with_prefers_reduced_motion(mut self, prefers: bool) -> Self {
+        self.prefers_reduced_motion = prefers;
+        self
+    }
+
+    /// Whether motion is reduced under the global policy
+    pub fn is_reduced(&self) -> bool {
+        self.reduces(None)
+    }
+
+    /// Whether motion is reduced under `policy`, falling back to the global one
+    pub fn reduces(&self, policy: Option<ReducedMotion>) -> bool {
+        match policy.unwrap_or(self.reduced_motion) {
+            ReducedMotion::Always => true,
+            ReducedMotion::Never => false,
+            ReducedMotion::User => self.prefers_reduced_motion,
+        }
+    }
+
+    /// Plays a single 60fps frame of every animation while paused
+    pub fn step(&mut self) {
+        self.steps += 1;
+    }
+
+    pub(crate) fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Animation time for a frame that took `dt` seconds
+    ///
+    /// `seen_steps` tracks the frame steps already played by the caller.
+    pub(crate) fn frame_time(&self, dt: f32, seen_steps: &mut u64) -> f32 {
+        let stepped = self.steps > *seen_steps;
+        *seen_steps = self.steps;
+        if !self.paused {
+            dt * self.time_scale.max(0.0)
+        } else if stepped {
+            STEP_SECS
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The [MotionSettings] of the closest provider, or the global ones
+pub fn use_motion_settings() -> Signal<MotionSettings> {
+    use_hook(|| {
+        try_consume_context().unwrap_or_else(|| {
+            provide_root_context(Signal::new_in_scope(
+                MotionSettings::default(),
+                ScopeId::ROOT,
+            ))
+        })
+    })
+}
+
+/// Provides separate [MotionSettings] to the motions below this component
+pub fn use_motion_settings_provider(settings: MotionSettings) -> Signal<MotionSettings> {
+    use_context_provider(|| Signal::new(settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_time() {
+        let mut seen = 0;
+        let mut settings = MotionSettings::new().with_time_scale(0.1);
+        assert!((settings.frame_time(0.016, &mut seen) - 0.0016).abs() < f32::EPSILON);
+
+        settings.paused = true;
+        assert_eq!(settings.frame_time(0.016, &mut seen), 0.0);
+        settings.step();
+        assert_eq!(settings.frame_time(0.016, &mut seen), STEP_SECS);
+        assert_eq!(settings.frame_time(0.016, &mut seen), 0.0);
+
+        // Steps taken while running aren't replayed once paused
+        settings.paused = false;
+        settings.step();
+        settings.frame_time(0.016, &mut seen);
+        settings.paused = true;
+        assert_eq!(settings.frame_time(0.016, &mut seen), 0.0);
+    }
+
+    #[test]
+    fn test_reduced_motion_policy() {
+        let settings = MotionSettings::new().with_prefers_reduced_motion(true);
+        assert!(settings.is_reduced());
+        assert!(!settings.reduces(Some(ReducedMotion::Never)));
+
+        let settings = settings.with_prefers_reduced_motion(false);
+        assert!(!settings.is_reduced());
+        assert!(settings.reduces(Some(ReducedMotion::Always)));
+        assert!(settings
+            .with_reduced_motion(ReducedMotion::Always)
+            .is_reduced());
+    }
+}
@@ -3,6 +3,15 @@
 //! Provides a physical spring model for smooth, natural-looking animations.
 //! Based on Hooke's law with damping for realistic motion.
 
+use std::f32::consts::{PI, TAU};
+
+use instant::Duration;
+
+// Share of the starting distance left when a spring counts as settled
+const SETTLE_TOLERANCE: f32 = 0.001;
+// Upper bound for springs that barely lose any energy
+const MAX_SETTLING_SECS: f32 = 10.0;
+
 /// Configuration for spring-based animations
 ///
 /// Uses a mass-spring-damper system to create natural motion.
@@ -48,6 +57,171 @@ impl Default for Spring {
     }
 }
 
+impl Spring {
+    /// Spring reaching its target in about `duration`, with `bounce` setting the overshoot
+    ///
+    /// `bounce` of 0.0 is critically damped, values towards 1.0 get bouncier and
+    /// negative values are overdamped. Like SwiftUI, `duration` is the perceived
+    /// duration; the final settle takes a little longer, see [`Spring::settling_time`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use dioxus_motion::prelude::*;
+    ///
+    /// let snappy = Spring::from_duration_bounce(Duration::from_millis(400), 0.2);
+    /// assert!(snappy.damping_ratio() < 1.0);
+    /// ```
+    pub fn from_duration_bounce(duration: Duration, bounce: f32) -> Self {
+        let duration = duration.as_secs_f32().max(f32::EPSILON);
+        let bounce = bounce.clamp(-0.95, 1.0);
+        let damping = if bounce >= 0.0 {
+            (1.0 - bounce) * 4.0 * PI / duration
+        } else {
+            4.0 * PI / (duration * (1.0 + bounce))
+        };
+
+        Self {
+            stiffness: (TAU / duration).powi(2),
+            damping,
+            mass: 1.0,
+            velocity: 0.0,
+        }
+    }
+
+    /// Fastest spring that doesn't overshoot, reaching its target in about `duration`
+    pub fn critically_damped(duration: Duration) -> Self {
+        Self::from_duration_bounce(duration, 0.0)
+    }
+
+    /// Soft and slow, with a small overshoot
+    pub fn gentle() -> Self {
+        Self::preset(120.0, 14.0)
+    }
+
+    /// Quick with a lot of bounce
+    pub fn wobbly() -> Self {
+        Self::preset(180.0, 12.0)
+    }
+
+    /// Fast and firm
+    pub fn stiff() -> Self {
+        Self::preset(210.0, 20.0)
+    }
+
+    /// Slow without overshoot
+    pub fn slow() -> Self {
+        Self::preset(280.0, 60.0)
+    }
+
+    /// Very slow and heavily damped
+    pub fn molasses() -> Self {
+        Self::preset(280.0, 120.0)
+    }
+
+    fn preset(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            ..Default::default()
+        }
+    }
+
+    /// Damping ratio: below 1.0 the spring oscillates, 1.0 is critically damped
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Closed-form solution of the damped harmonic oscillator after `t` seconds
+    ///
+    /// The displacement from the target and the velocity are linear in their starting
+    /// values, so the result holds the coefficients to combine them with. Covers the
+    /// under-damped, critically damped and over-damped cases.
+    pub fn response(&self, t: f32) -> SpringResponse {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping_ratio();
+        // Without a restoring force there is nothing to solve, settle right away
+        if !omega.is_normal() || !zeta.is_finite() || zeta < 0.0 {
+            return SpringResponse {
+                position: (0.0, 0.0),
+                velocity: (0.0, 0.0),
+            };
+        }
+
+        if (zeta - 1.0).abs() < 1e-4 {
+            // x(t) = e^(-wt) (x0 + (v0 + w x0) t)
+            let decay = (-omega * t).exp();
+            SpringResponse {
+                position: (decay * (1.0 + omega * t), decay * t),
+                velocity: (-decay * omega * omega * t, decay * (1.0 - omega * t)),
+            }
+        } else if zeta < 1.0 {
+            // x(t) = e^(-at) (x0 cos(wd t) + (v0 + a x0) / wd sin(wd t))
+            let a = zeta * omega;
+            let wd = omega * (1.0 - zeta * zeta).sqrt();
+            let decay = (-a * t).exp();
+            let (sin, cos) = (wd * t).sin_cos();
+            SpringResponse {
+                position: (decay * (cos + a / wd * sin), decay * sin / wd),
+                velocity: (
+                    -decay * omega * omega / wd * sin,
+                    decay * (cos - a / wd * sin),
+                ),
+            }
+        } else {
+            // x(t) = c1 e^(r1 t) + c2 e^(r2 t)
+            let root = omega * (zeta * zeta - 1.0).sqrt();
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+            let span = r1 - r2;
+            SpringResponse {
+                position: ((r1 * e2 - r2 * e1) / span, (e1 - e2) / span),
+                velocity: (r1 * r2 * (e2 - e1) / span, (r1 * e1 - r2 * e2) / span),
+            }
+        }
+    }
+
+    /// Estimated time until the spring settles, starting at rest
+    ///
+    /// Computed from the decay of the oscillation envelope: settled means within 0.1%
+    /// of the starting distance. Springs without damping are capped at 10 seconds.
+    pub fn settling_time(&self) -> Duration {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping_ratio();
+        // Also catches NaN from zero or negative parameters
+        if !omega.is_normal() || !zeta.is_normal() || zeta < 0.0 {
+            return Duration::from_secs_f32(MAX_SETTLING_SECS);
+        }
+
+        let log_tolerance = -SETTLE_TOLERANCE.ln();
+        let seconds = if (zeta - 1.0).abs() < 1e-3 {
+            // Critically damped: solve (1 + x) e^-x = tolerance for x = omega * t
+            let mut x = log_tolerance;
+            for _ in 0..8 {
+                x = log_tolerance + (1.0 + x).ln();
+            }
+            x / omega
+        } else if zeta < 1.0 {
+            log_tolerance / (zeta * omega)
+        } else {
+            // Overdamped: the slower of the two exponentials decides
+            log_tolerance / (omega * (zeta - (zeta * zeta - 1.0).sqrt()))
+        };
+
+        Duration::from_secs_f32(seconds.min(MAX_SETTLING_SECS))
+    }
+}
+
+/// Coefficients of a spring's closed-form solution, see [`Spring::response`]
+///
+/// Each pair weighs the starting displacement and the starting velocity:
+/// `x(t) = position.0 * x0 + position.1 * v0`, `v(t) = velocity.0 * x0 + velocity.1 * v0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringResponse {
+    pub position: (f32, f32),
+    pub velocity: (f32, f32),
+}
+
 /// Represents the current state of a spring animation
 ///
 /// Used to track whether the spring is still moving or has settled
@@ -86,4 +260,91 @@ mod tests {
         assert_eq!(spring.mass, 2.0);
         assert_eq!(spring.velocity, 5.0);
     }
+
+    #[test]
+    fn test_spring_from_duration_bounce() {
+        let critical = Spring::critically_damped(Duration::from_millis(500));
+        assert!((critical.damping_ratio() - 1.0).abs() < 1e-4);
+        assert!((critical.stiffness - (TAU / 0.5).powi(2)).abs() < 1e-2);
+
+        let bouncy = Spring::from_duration_bounce(Duration::from_millis(500), 0.5);
+        assert!((bouncy.damping_ratio() - 0.5).abs() < 1e-4);
+
+        let overdamped = Spring::from_duration_bounce(Duration::from_millis(500), -0.5);
+        assert!(overdamped.damping_ratio() > 1.0);
+    }
+
+    #[test]
+    fn test_spring_settling_time() {
+        // zeta = 0.5, omega = 10: envelope e^(-5t) reaches 0.1% after ln(1000) / 5
+        let expected = 1000.0f32.ln() / 5.0;
+        let settling = Spring::default().settling_time().as_secs_f32();
+        assert!((settling - expected).abs() < 1e-4);
+
+        // Critically damped settles faster than both neighbours
+        let duration = Duration::from_millis(500);
+        let critical = Spring::critically_damped(duration).settling_time();
+        assert!(critical < Spring::from_duration_bounce(duration, 0.3).settling_time());
+        assert!(critical < Spring::from_duration_bounce(duration, -0.3).settling_time());
+
+        let undamped = Spring {
+            damping: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(undamped.settling_time(), Duration::from_secs(10));
+    }
+
+    // Displacement and velocity after `t` starting from `x0`, `v0`
+    fn solve(spring: Spring, x0: f32, v0: f32, t: f32) -> (f32, f32) {
+        let r = spring.response(t);
+        (
+            r.position.0 * x0 + r.position.1 * v0,
+            r.velocity.0 * x0 + r.velocity.1 * v0,
+        )
+    }
+
+    #[test]
+    fn test_spring_response_solves_oscillator() {
+        let springs = [
+            Spring::default(),
+            Spring::critically_damped(Duration::from_millis(400)),
+            Spring::slow(),
+        ];
+        for spring in springs {
+            let (x, v) = solve(spring, 1.0, 2.0, 0.0);
+            assert!((x - 1.0).abs() < 1e-6 && (v - 2.0).abs() < 1e-6);
+
+            // m x'' + c x' + k x = 0, checked with central differences
+            let (t, h) = (0.2, 1e-3);
+            let (x, v) = solve(spring, 10.0, 5.0, t);
+            let (x_prev, v_prev) = solve(spring, 10.0, 5.0, t - h);
+            let (x_next, v_next) = solve(spring, 10.0, 5.0, t + h);
+            let tolerance = 1e-2 * (1.0 + spring.stiffness * x.abs());
+            assert!(((x_next - x_prev) / (2.0 * h) - v).abs() < 1e-2 * (1.0 + v.abs()));
+            let acceleration = (v_next - v_prev) / (2.0 * h);
+            assert!(
+                (spring.mass * acceleration + spring.damping * v + spring.stiffness * x).abs()
+                    < tolerance
+            );
+        }
+    }
+
+    #[test]
+    fn test_spring_response_composes() {
+        // Two half steps land exactly where one full step does
+        for spring in [Spring::wobbly(), Spring::molasses()] {
+            let (x, v) = solve(spring, 50.0, -20.0, 0.15);
+            let (x, v) = solve(spring, x, v, 0.15);
+            let (x_full, v_full) = solve(spring, 50.0, -20.0, 0.3);
+            assert!((x - x_full).abs() < 1e-3);
+            assert!((v - v_full).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_spring_presets() {
+        assert!(Spring::wobbly().damping_ratio() < Spring::gentle().damping_ratio());
+        assert!(Spring::molasses().settling_time() > Spring::stiff().settling_time());
+        assert!(Spring::slow().damping_ratio() > 1.0);
+    }
 }
@@ -0,0 +1,292 @@
+//! Stagger module
+//!
+//! Spreads the start of several animations over time. [`stagger`] computes the
+//! delays for a list of items, and [`use_motion_list`] drives a list of motions that
+//! can be animated in one go with those delays applied.
+
+use dioxus::prelude::*;
+use instant::Duration;
+
+use crate::animations::driver::use_frame_ticker;
+use crate::animations::easing::Easing;
+use crate::animations::settings::use_motion_settings;
+use crate::animations::utils::{Animatable, AnimationConfig};
+use crate::{use_clock, Motion};
+
+/// Item a stagger starts from; delays grow with the distance to it
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum StaggerFrom {
+    #[default]
+    First,
+    Last,
+    Center,
+    Index(usize),
+}
+
+/// Delays spread over a list of items, created with [`stagger`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stagger {
+    /// Delay between neighbouring items
+    pub each: Duration,
+    /// Item starting first
+    pub from: StaggerFrom,
+    /// Distributes the delays over the whole range instead of evenly
+    pub easing: Option<Easing>,
+}
+
+/// Staggers items by `each`, counting from `from`
+///
+/// With an `easing`, the total range of delays stays the same but is distributed
+/// along the curve, e.g. bunching the first items together with an ease-in.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+///
+/// let delays = stagger(Duration::from_millis(50), StaggerFrom::Center, None).delays(5);
+/// assert_eq!(delays[2], Duration::ZERO);
+/// assert_eq!(delays[0], Duration::from_millis(100));
+/// ```
+pub fn stagger(each: Duration, from: StaggerFrom, easing: Option<Easing>) -> Stagger {
+    Stagger { each, from, easing }
+}
+
+impl Stagger {
+    /// Delay of the item at `index` in a list of `count` items
+    pub fn delay(&self, index: usize, count: usize) -> Duration {
+        let distance = |index: usize| {
+            let index = index as f64;
+            let last = count.saturating_sub(1) as f64;
+            let distance = match self.from {
+                StaggerFrom::First => index,
+                StaggerFrom::Last => last - index,
+                StaggerFrom::Center => (index - last / 2.0).abs(),
+                StaggerFrom::Index(origin) => (index - origin as f64).abs(),
+            };
+            distance.max(0.0)
+        };
+
+        let Some(easing) = &self.easing else {
+            return self.each.mul_f64(distance(index));
+        };
+        let max = (0..count).map(distance).fold(0.0, f64::max);
+        if max <= 0.0 {
+            return Duration::ZERO;
+        }
+        let eased = easing.apply((distance(index) / max) as f32) as f64;
+        self.each.mul_f64((max * eased).max(0.0))
+    }
+
+    /// Delays of all items in a list of `count` items
+    pub fn delays(&self, count: usize) -> Vec<Duration> {
+        (0..count).map(|index| self.delay(index, count)).collect()
+    }
+}
+
+/// A list of motions driven together, returned by [`use_motion_list`]
+#[derive(Clone, Copy)]
+pub struct MotionList<T: Animatable> {
+    motions: Signal<Vec<Motion<T>>>,
+}
+
+impl<T: Animatable> MotionList<T> {
+    pub fn len(&self) -> usize {
+        self.motions.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.motions.read().is_empty()
+    }
+
+    pub fn get_value(&self, index: usize) -> Option<T> {
+        self.motions.read().get(index).map(Motion::value)
+    }
+
+    /// Current values of all motions
+    pub fn values(&self) -> Vec<T> {
+        self.motions.read().iter().map(Motion::value).collect()
+    }
+
+    /// Whether any motion in the list is animating
+    pub fn is_running(&self) -> bool {
+        self.motions.read().iter().any(Motion::is_running)
+    }
+
+    pub fn animate_to(&mut self, index: usize, target: T, config: AnimationConfig) {
+        if let Some(motion) = self.motions.write().get_mut(index) {
+            motion.animate_to(target, config);
+        }
+    }
+
+    /// Animates every motion to `target`, delaying each by `stagger` on top of the config's delay
+    ///
+    /// The config's `on_complete` runs once per motion.
+    pub fn animate_all(&mut self, target: T, config: AnimationConfig, stagger: &Stagger) {
+        self.animate_each(std::iter::repeat(target), config, stagger);
+    }
+
+    /// Animates each motion to its own target, delaying each by `stagger`
+    ///
+    /// Motions without a matching target are left alone.
+    pub fn animate_each(
+        &mut self,
+        targets: impl IntoIterator<Item = T>,
+        config: AnimationConfig,
+        stagger: &Stagger,
+    ) {
+        let mut motions = self.motions.write();
+        let count = motions.len();
+        for (index, (motion, target)) in motions.iter_mut().zip(targets).enumerate() {
+            let delay = config.delay + stagger.delay(index, count);
+            motion.animate_to(target, config.clone().with_delay(delay));
+        }
+    }
+
+    pub fn stop_all(&mut self) {
+        for motion in self.motions.write().iter_mut() {
+            motion.stop();
+        }
+    }
+
+    /// Grows or shrinks the list, new motions start at `initial`
+    pub fn resize(&mut self, len: usize, initial: T) {
+        self.motions
+            .write()
+            .resize_with(len, || Motion::new(initial));
+    }
+}
+
+/// Creates a list of `count` motions starting at `initial`, driven every frame
+///
+/// `count` is read on the first render; use [`MotionList::resize`] when the list
+/// changes length.
+///
+/// # Examples
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn Cards() -> Element {
+///     let mut cards = use_motion_list(5, 0.0f32);
+///
+///     use_effect(move || {
+///         cards.animate_all(
+///             1.0,
+///             AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+///             &stagger(Duration::from_millis(60), StaggerFrom::First, None),
+///         );
+///     });
+///
+///     rsx! {
+///         for (index, opacity) in cards.values().into_iter().enumerate() {
+///             div { key: "{index}", style: "opacity: {opacity}", "Card {index}" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_motion_list<T: Animatable>(count: usize, initial: T) -> MotionList<T> {
+    let mut motions = use_signal(|| vec![Motion::new(initial); count]);
+    let clock = use_clock();
+    let settings = use_motion_settings();
+    let mut seen_steps = settings.peek().steps();
+
+    use_frame_ticker(
+        clock,
+        move || motions.read().iter().any(Motion::is_running),
+        move |dt| {
+            if !motions.peek().iter().any(Motion::is_running) {
+                return false;
+            }
+            let settings = *settings.peek();
+            let frame_time = settings.frame_time(dt, &mut seen_steps);
+            if frame_time > 0.0 || settings.is_reduced() {
+                for motion in motions.write().iter_mut() {
+                    if settings.reduces(motion.config.reduced_motion) {
+                        motion.finish();
+                    } else if motion.is_running() {
+                        motion.update(frame_time);
+                    }
+                }
+            }
+            motions.peek().iter().any(Motion::is_running)
+        },
+    );
+
+    MotionList { motions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{AnimationMode, Tween};
+    use crate::testing::AppHarness;
+
+    fn millis(delays: Vec<Duration>) -> Vec<u128> {
+        delays.iter().map(Duration::as_millis).collect()
+    }
+
+    #[test]
+    fn test_stagger_from() {
+        let each = Duration::from_millis(100);
+        assert_eq!(
+            millis(stagger(each, StaggerFrom::First, None).delays(4)),
+            vec![0, 100, 200, 300]
+        );
+        assert_eq!(
+            millis(stagger(each, StaggerFrom::Last, None).delays(4)),
+            vec![300, 200, 100, 0]
+        );
+        assert_eq!(
+            millis(stagger(each, StaggerFrom::Center, None).delays(4)),
+            vec![150, 50, 50, 150]
+        );
+        assert_eq!(
+            millis(stagger(each, StaggerFrom::Index(1), None).delays(4)),
+            vec![100, 0, 100, 200]
+        );
+    }
+
+    #[test]
+    fn test_stagger_easing() {
+        let eased = stagger(
+            Duration::from_millis(100),
+            StaggerFrom::First,
+            Some(Easing::steps(2, Default::default())),
+        );
+        // Same 300ms range, distributed along the curve
+        assert_eq!(millis(eased.delays(4)), vec![0, 0, 150, 300]);
+        assert_eq!(eased.delay(0, 1), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_stagger_applies_to_motions() {
+        let (harness, mut list) = AppHarness::mount_hook(|| use_motion_list(3, 0.0f32));
+        let mut harness = harness.with_frame_duration(Duration::from_millis(10));
+        harness.update(|| {
+            list.animate_all(
+                1.0,
+                AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100))))
+                    .with_delay(Duration::from_millis(50)),
+                &stagger(Duration::from_millis(100), StaggerFrom::First, None),
+            )
+        });
+
+        // Time of the first frame each motion moves on
+        let mut starts = [None; 3];
+        for frame in 1..=40u64 {
+            harness.step();
+            let values = harness.read(|| list.values());
+            for (start, value) in starts.iter_mut().zip(values) {
+                if start.is_none() && value > 0.0 {
+                    *start = Some(Duration::from_millis(frame * 10));
+                }
+            }
+        }
+        // Each waits for the config's 50ms plus its stagger, then moves on the next frame
+        assert_eq!(
+            starts,
+            [50, 150, 250].map(|delay| Some(Duration::from_millis(delay + 10)))
+        );
+        assert_eq!(harness.read(|| list.values()), vec![1.0; 3]);
+    }
+}
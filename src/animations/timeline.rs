@@ -0,0 +1,697 @@
+//! Timeline module for orchestrating several motions
+//!
+//! A timeline places `animate_to` calls on different [`AnimationManager`]s along a
+//! single playhead. Entries are positioned at absolute times, relative to the end of
+//! the timeline (`"+=100ms"`, `"-=100ms"` for overlaps), relative to the previous
+//! entry (`"<"`) or at named labels. The whole group is controlled at once: play,
+//! pause, seek, reverse and time scale.
+//!
+//! Every frame the timeline renders each entry at its local time, so seeking and
+//! reversing are deterministic and never depend on frame timing.
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use instant::Duration;
+
+use crate::animations::driver::use_frame_ticker;
+use crate::animations::settings::use_motion_settings;
+use crate::animations::utils::{Animatable, AnimationConfig, AnimationMode};
+use crate::{use_clock, AnimationManager, Motion};
+
+/// Where an entry or label is placed on the timeline
+///
+/// Strings convert with GSAP-like syntax:
+/// - `"+=100ms"` / `"-=0.2s"`: relative to the current end of the timeline
+/// - `"<"`, `"<+=50ms"`: relative to the start of the previously added entry
+/// - `"label"`, `"label-=100ms"`: relative to a label (unknown labels are created at the end)
+///
+/// Times without a unit are seconds.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimelinePosition {
+    /// Absolute time from the start of the timeline
+    At(Duration),
+    /// Offset in seconds from the current end of the timeline
+    End(f32),
+    /// Offset in seconds from the start of the previous entry
+    PreviousStart(f32),
+    /// Offset in seconds from a named label
+    Label(String, f32),
+}
+
+impl Default for TimelinePosition {
+    fn default() -> Self {
+        Self::End(0.0)
+    }
+}
+
+impl From<Duration> for TimelinePosition {
+    fn from(duration: Duration) -> Self {
+        Self::At(duration)
+    }
+}
+
+impl From<&str> for TimelinePosition {
+    fn from(position: &str) -> Self {
+        let position = position.trim();
+
+        if position.is_empty() {
+            return Self::End(0.0);
+        }
+        if position.starts_with("+=") || position.starts_with("-=") {
+            return Self::End(parse_offset(position));
+        }
+        if let Some(offset) = position.strip_prefix('<') {
+            return Self::PreviousStart(parse_offset(offset));
+        }
+
+        position
+            .find("+=")
+            .or_else(|| position.find("-="))
+            .map_or_else(
+                || Self::Label(position.to_string(), 0.0),
+                |index| {
+                    Self::Label(
+                        position[..index].trim().to_string(),
+                        parse_offset(&position[index..]),
+                    )
+                },
+            )
+    }
+}
+
+/// Parses `"+=100ms"`, `"-=0.5s"` or `"2"` into signed seconds; invalid input is 0.0
+fn parse_offset(offset: &str) -> f32 {
+    let offset = offset.trim();
+    let (sign, time) = offset.strip_prefix("-=").map_or_else(
+        || (1.0, offset.strip_prefix("+=").unwrap_or(offset)),
+        |time| (-1.0, time),
+    );
+
+    let time = time.trim();
+    let seconds = time.strip_suffix("ms").map_or_else(
+        || time.strip_suffix('s').unwrap_or(time).trim().parse::<f32>(),
+        |ms| ms.trim().parse::<f32>().map(|ms| ms / 1000.0),
+    );
+
+    sign * seconds.unwrap_or(0.0)
+}
+
+/// A type-erased entry on the timeline
+trait TimelineTrack {
+    fn start(&self) -> f32;
+    fn end(&self) -> f32;
+    /// Writes the value at timeline `time` into the manager
+    fn render(&mut self, time: f32);
+    /// Restores the starting value when the playhead moves back before the entry
+    fn rewind(&mut self);
+    fn complete(&mut self);
+}
+
+struct Track<T: Animatable, M: AnimationManager<T>> {
+    manager: M,
+    target: T,
+    config: AnimationConfig,
+    start: f32,
+    delay: f32,
+    duration: f32,
+    /// Captured from the manager the first time the entry is reached
+    from: Option<T>,
+    /// Spring or decay simulation and its local time, reused while the playhead moves forward
+    simulation: Option<(Motion<T>, f32)>,
+}
+
+impl<T: Animatable, M: AnimationManager<T>> Track<T, M> {
+    const SIMULATION_STEP: f32 = 1.0 / 120.0;
+
+    fn value_at(&mut self, from: T, local: f32) -> T {
+        let local = local - self.delay;
+        if local <= 0.0 {
+            return from;
+        }
+        // A decay comes to rest wherever its velocity carries it, not at the target
+        let decays = matches!(self.config.mode, AnimationMode::Decay(_));
+        if local >= self.duration && !decays {
+            return self.target;
+        }
+        let local = local.min(self.duration);
+
+        match &self.config.mode {
+            AnimationMode::Tween(tween) => {
+                let progress = local / self.duration;
+                from.interpolate(&self.target, tween.easing.apply(progress))
+            }
+            AnimationMode::Spring(_) | AnimationMode::Decay(_) => {
+                let stale = self
+                    .simulation
+                    .as_ref()
+                    .is_none_or(|(_, simulated)| *simulated > local);
+                if stale {
+                    let mut motion = Motion::new(from);
+                    motion.animate_to(self.target, AnimationConfig::new(self.config.mode.clone()));
+                    self.simulation = Some((motion, 0.0));
+                }
+
+                let Some((motion, simulated)) = self.simulation.as_mut() else {
+                    return from;
+                };
+                while *simulated + Self::SIMULATION_STEP <= local {
+                    motion.update(Self::SIMULATION_STEP);
+                    *simulated += Self::SIMULATION_STEP;
+                }
+                motion.value()
+            }
+        }
+    }
+}
+
+impl<T: Animatable, M: AnimationManager<T>> TimelineTrack for Track<T, M> {
+    fn start(&self) -> f32 {
+        self.start
+    }
+
+    fn end(&self) -> f32 {
+        self.start + self.delay + self.duration
+    }
+
+    fn render(&mut self, time: f32) {
+        let from = *self.from.get_or_insert_with(|| self.manager.get_value());
+        let value = self.value_at(from, time - self.start);
+        self.manager.set_value(value);
+    }
+
+    fn rewind(&mut self) {
+        if let Some(from) = self.from {
+            self.manager.set_value(from);
+        }
+    }
+
+    fn complete(&mut self) {
+        self.config.execute_completion();
+    }
+}
+
+struct TimelineEntry {
+    track: Box<dyn TimelineTrack>,
+    completed: bool,
+}
+
+/// Orchestrates animations on several managers along one playhead
+///
+/// # Examples
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn app() -> Element {
+///     let card = use_motion(Transform::identity());
+///     let opacity = use_motion(0.0f32);
+///     let mut timeline = use_timeline();
+///
+///     use_effect(move || {
+///         let tween = AnimationConfig::new(AnimationMode::Tween(Tween::default()));
+///         timeline.set(
+///             Timeline::new()
+///                 .add(opacity, 1.0, tween.clone(), "+=0ms")
+///                 .add_label("slide", "-=100ms")
+///                 .add(card, Transform::new(0.0, -20.0, 1.0, 0.0), tween, "slide"),
+///         );
+///         timeline.write().play();
+///     });
+///
+///     rsx! { div { style: "opacity: {opacity.get_value()}" } }
+/// }
+/// ```
+pub struct Timeline {
+    entries: Vec<TimelineEntry>,
+    labels: HashMap<String, f32>,
+    previous_start: f32,
+    duration: f32,
+    time: f32,
+    time_scale: f32,
+    reversed: bool,
+    playing: bool,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            labels: HashMap::new(),
+            previous_start: 0.0,
+            duration: 0.0,
+            time: 0.0,
+            time_scale: 1.0,
+            reversed: false,
+            playing: false,
+            on_complete: None,
+        }
+    }
+}
+
+impl Timeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves a position to seconds, creating unknown labels at the end
+    fn resolve(&mut self, position: TimelinePosition) -> f32 {
+        let time = match position {
+            TimelinePosition::At(time) => time.as_secs_f32(),
+            TimelinePosition::End(offset) => self.duration + offset,
+            TimelinePosition::PreviousStart(offset) => self.previous_start + offset,
+            TimelinePosition::Label(label, offset) => {
+                let duration = self.duration;
+                *self.labels.entry(label).or_insert(duration) + offset
+            }
+        };
+        time.max(0.0)
+    }
+
+    /// Schedules `manager.animate_to(target, config)` at `position`
+    ///
+    /// The config's mode, delay and completion callback are honoured; loop modes are
+    /// not, since the timeline itself decides when the entry plays.
+    pub fn add<T: Animatable, M: AnimationManager<T> + 'static>(
+        mut self,
+        manager: M,
+        target: T,
+        config: AnimationConfig,
+        position: impl Into<TimelinePosition>,
+    ) -> Self {
+        let start = self.resolve(position.into());
+        let duration = match &config.mode {
+            AnimationMode::Tween(tween) => tween.duration,
+            AnimationMode::Spring(_) | AnimationMode::Decay(_) => {
+                AnimationConfig::new(config.mode.clone()).get_duration()
+            }
+        };
+
+        let track = Track {
+            manager,
+            target,
+            delay: config.delay.as_secs_f32(),
+            duration: duration.as_secs_f32(),
+            config,
+            start,
+            from: None,
+            simulation: None,
+        };
+
+        self.previous_start = start;
+        self.duration = self.duration.max(track.end());
+        self.entries.push(TimelineEntry {
+            track: Box::new(track),
+            completed: false,
+        });
+        self
+    }
+
+    /// Adds a named label that later entries can be positioned against
+    pub fn add_label(mut self, label: &str, position: impl Into<TimelinePosition>) -> Self {
+        let time = self.resolve(position.into());
+        self.labels.insert(label.to_string(), time);
+        self
+    }
+
+    /// Sets a callback fired when the playhead reaches either end while playing
+    pub fn with_on_complete<F: FnMut() + 'static>(mut self, f: F) -> Self {
+        self.on_complete = Some(Box::new(f));
+        self
+    }
+
+    /// Starts or resumes playback in the current direction
+    pub fn play(&mut self) {
+        let at_end = if self.reversed {
+            self.time <= 0.0
+        } else {
+            self.time >= self.duration
+        };
+        if at_end {
+            self.time = if self.reversed { self.duration } else { 0.0 };
+        }
+        self.playing = true;
+        self.render();
+    }
+
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Flips the playback direction and keeps playing from the current time
+    pub fn reverse(&mut self) {
+        self.reversed = !self.reversed;
+        self.playing = true;
+    }
+
+    /// Plays again from the start, forwards
+    pub fn restart(&mut self) {
+        self.reversed = false;
+        self.time = 0.0;
+        self.playing = true;
+        self.render();
+    }
+
+    /// Moves the playhead to `time` without changing the play state
+    pub fn seek(&mut self, time: Duration) {
+        self.time = time.as_secs_f32().min(self.duration);
+        self.render();
+    }
+
+    /// Moves the playhead to a fraction (0.0-1.0) of the total duration
+    pub fn seek_progress(&mut self, progress: f32) {
+        self.time = progress.clamp(0.0, 1.0) * self.duration;
+        self.render();
+    }
+
+    /// Moves the playhead to a label, if it exists
+    pub fn seek_label(&mut self, label: &str) {
+        if let Some(time) = self.labels.get(label).copied() {
+            self.time = time.min(self.duration);
+            self.render();
+        }
+    }
+
+    /// Playback speed multiplier (1.0 = real time, 0.5 = half speed)
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.max(0.0);
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Current playhead position
+    pub fn time(&self) -> Duration {
+        Duration::from_secs_f32(self.time)
+    }
+
+    /// Total length of the timeline
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f32(self.duration)
+    }
+
+    pub fn progress(&self) -> f32 {
+        if self.duration == 0.0 {
+            1.0
+        } else {
+            self.time / self.duration
+        }
+    }
+
+    /// Jumps to the end of the timeline, or to its start when reversed, completing it
+    ///
+    /// Works while paused too: the managers jump to their final values and
+    /// `on_complete` fires.
+    pub fn finish(&mut self) {
+        self.time = if self.reversed { 0.0 } else { self.duration };
+        // Render as playing so entries reaching their end run their own completion
+        self.playing = true;
+        self.render();
+        self.complete();
+    }
+
+    /// Advances the playhead by `dt` seconds of real time
+    ///
+    /// Returns whether the timeline is still playing.
+    pub fn tick(&mut self, dt: f32) -> bool {
+        if !self.playing {
+            return false;
+        }
+
+        let step = dt * self.time_scale;
+        self.time = if self.reversed {
+            (self.time - step).max(0.0)
+        } else {
+            (self.time + step).min(self.duration)
+        };
+        self.render();
+
+        let finished = if self.reversed {
+            self.time <= 0.0
+        } else {
+            self.time >= self.duration
+        };
+        if finished {
+            self.complete();
+        }
+
+        self.playing
+    }
+
+    fn complete(&mut self) {
+        self.playing = false;
+        if let Some(on_complete) = self.on_complete.as_mut() {
+            on_complete();
+        }
+    }
+
+    fn render(&mut self) {
+        let time = self.time;
+        let mut order: Vec<usize> = (0..self.entries.len()).collect();
+        order.sort_by(|a, b| {
+            self.entries[*a]
+                .track
+                .start()
+                .total_cmp(&self.entries[*b].track.start())
+        });
+
+        // Entries not reached yet restore their starting value, latest first so the
+        // earliest entry on a shared manager wins
+        for &index in order.iter().rev() {
+            let entry = &mut self.entries[index];
+            if time < entry.track.start() {
+                entry.track.rewind();
+                entry.completed = false;
+            }
+        }
+
+        // Entries already reached render in start order so later ones take precedence
+        for &index in order.iter() {
+            let entry = &mut self.entries[index];
+            if time >= entry.track.start() {
+                entry.track.render(time);
+
+                let ended = time >= entry.track.end();
+                if ended && !entry.completed && self.playing && !self.reversed {
+                    entry.track.complete();
+                }
+                entry.completed = ended;
+            }
+        }
+    }
+}
+
+/// Creates a timeline that is driven every frame while playing
+///
+/// Build the timeline with [`Timeline::new`] and store it with `Signal::set`, then
+/// control playback through `write()`.
+pub fn use_timeline() -> Signal<Timeline> {
+    let mut timeline = use_signal(Timeline::new);
+    let clock = use_clock();
+    let settings = use_motion_settings();
+    let mut seen_steps = settings.peek().steps();
+
+    use_frame_ticker(
+        clock,
+        move || timeline.read().is_playing(),
+        move |dt| {
+            if !timeline.peek().is_playing() {
+                return false;
+            }
+            let settings = *settings.peek();
+            let frame_time = settings.frame_time(dt, &mut seen_steps);
+            if settings.is_reduced() {
+                timeline.write().finish();
+            } else if frame_time > 0.0 {
+                timeline.write().tick(frame_time);
+            }
+            timeline.peek().is_playing()
+        },
+    );
+
+    timeline
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Tween;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    thread_local! {
+        // Values of the test's managers, dropped with the test thread
+        static VALUES: RefCell<Vec<Rc<Cell<f32>>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Minimal manager backed by shared state, so tracks can run without a Dioxus runtime
+    ///
+    /// `AnimationManager` is `Copy`, so the manager is an index into `VALUES`. Only
+    /// implements the required methods, like a manager written before the defaults
+    #[derive(Clone, Copy)]
+    struct TestManager(usize);
+
+    impl TestManager {
+        fn value(&self) -> Rc<Cell<f32>> {
+            VALUES.with(|values| values.borrow()[self.0].clone())
+        }
+    }
+
+    impl AnimationManager<f32> for TestManager {
+        fn new(initial: f32) -> Self {
+            VALUES.with(|values| {
+                let mut values = values.borrow_mut();
+                values.push(Rc::new(Cell::new(initial)));
+                Self(values.len() - 1)
+            })
+        }
+        fn animate_to(&mut self, target: f32, _config: AnimationConfig) {
+            self.value().set(target);
+        }
+        fn animate_sequence(&mut self, _sequence: crate::AnimationSequence<f32>) {}
+        fn update(&mut self, _dt: f32) -> bool {
+            false
+        }
+        fn get_value(&self) -> f32 {
+            self.value().get()
+        }
+        fn is_running(&self) -> bool {
+            false
+        }
+        fn reset(&mut self) {}
+        fn stop(&mut self) {}
+        fn delay(&mut self, _duration: Duration) {}
+    }
+
+    fn linear(ms: u64) -> AnimationConfig {
+        AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(ms))))
+    }
+
+    #[test]
+    fn test_parse_positions() {
+        assert_eq!(
+            TimelinePosition::from("-=100ms"),
+            TimelinePosition::End(-0.1)
+        );
+        assert_eq!(TimelinePosition::from("+=0.5s"), TimelinePosition::End(0.5));
+        assert_eq!(
+            TimelinePosition::from("<"),
+            TimelinePosition::PreviousStart(0.0)
+        );
+        assert_eq!(
+            TimelinePosition::from("intro-=200ms"),
+            TimelinePosition::Label("intro".to_string(), -0.2)
+        );
+        assert_eq!(
+            TimelinePosition::from("intro"),
+            TimelinePosition::Label("intro".to_string(), 0.0)
+        );
+    }
+
+    #[test]
+    fn test_overlapping_layout() {
+        let a = TestManager::new(0.0);
+        let b = TestManager::new(0.0);
+        let timeline = Timeline::new()
+            .add(a, 1.0, linear(1000), "+=0ms")
+            .add(b, 1.0, linear(1000), "-=500ms")
+            .add_label("end", "+=0ms");
+
+        assert_eq!(timeline.duration(), Duration::from_millis(1500));
+        assert_eq!(timeline.labels.get("end"), Some(&1.5));
+    }
+
+    #[test]
+    fn test_seek_and_reverse() {
+        let a = TestManager::new(0.0);
+        let b = TestManager::new(0.0);
+        let mut timeline =
+            Timeline::new()
+                .add(a, 10.0, linear(1000), "+=0ms")
+                .add(b, 20.0, linear(1000), "+=0ms");
+
+        timeline.seek(Duration::from_millis(1500));
+        assert_eq!(a.get_value(), 10.0);
+        assert!((b.get_value() - 10.0).abs() < 1e-4);
+
+        timeline.seek(Duration::from_millis(500));
+        assert!((a.get_value() - 5.0).abs() < 1e-4);
+        assert_eq!(b.get_value(), 0.0);
+
+        timeline.play();
+        timeline.reverse();
+        timeline.tick(0.25);
+        assert!((a.get_value() - 2.5).abs() < 1e-4);
+        timeline.tick(1.0);
+        assert!(!timeline.is_playing());
+        assert_eq!(a.get_value(), 0.0);
+    }
+
+    #[test]
+    fn test_time_scale_and_completion() {
+        let a = TestManager::new(0.0);
+        let done = Rc::new(Cell::new(false));
+        let flag = done.clone();
+        let mut timeline = Timeline::new()
+            .add(a, 1.0, linear(1000), "+=0ms")
+            .with_on_complete(move || flag.set(true));
+
+        timeline.set_time_scale(0.5);
+        timeline.play();
+        timeline.tick(1.0);
+        assert!((a.get_value() - 0.5).abs() < 1e-4);
+        assert!(!done.get());
+
+        timeline.tick(1.0);
+        assert_eq!(a.get_value(), 1.0);
+        assert!(done.get());
+    }
+
+    #[test]
+    fn test_finish_while_paused() {
+        let a = TestManager::new(0.0);
+        let done = Rc::new(Cell::new(false));
+        let flag = done.clone();
+        let mut timeline = Timeline::new()
+            .add(a, 1.0, linear(1000), "+=0ms")
+            .with_on_complete(move || flag.set(true));
+
+        timeline.seek(Duration::from_millis(250));
+        assert!(!timeline.is_playing());
+
+        timeline.finish();
+        assert_eq!(a.get_value(), 1.0);
+        assert_eq!(timeline.progress(), 1.0);
+        assert!(!timeline.is_playing());
+        assert!(done.get());
+    }
+
+    #[test]
+    fn test_spring_entry_settles_on_target() {
+        let a = TestManager::new(0.0);
+        let spring = AnimationConfig::new(AnimationMode::Spring(Default::default()));
+        let mut timeline = Timeline::new().add(a, 100.0, spring, "+=0ms");
+
+        timeline.seek(Duration::from_millis(100));
+        let early = a.get_value();
+        assert!(early > 0.0 && early < 100.0);
+
+        // Seeking backwards restarts the simulation deterministically
+        timeline.seek(Duration::from_millis(300));
+        timeline.seek(Duration::from_millis(100));
+        assert_eq!(a.get_value(), early);
+
+        timeline.seek(timeline.duration());
+        assert_eq!(a.get_value(), 100.0);
+    }
+}
@@ -81,6 +81,14 @@ impl Animatable for f32 {
     fn interpolate(&self, target: &Self, t: f32) -> Self {
         self + (target - self) * t
     }
+
+    fn to_scalar(&self) -> Option<f32> {
+        Some(*self)
+    }
+
+    fn from_scalar(value: f32) -> Option<Self> {
+        Some(value)
+    }
 }
 
 /// Implementation of Animatable for Transform
@@ -3,11 +3,15 @@
 //! Provides time-based animation with customizable easing functions.
 //! Supports duration and interpolation control for smooth animations.
 
-use easer::functions::{Easing, Linear};
+use easer::functions::{Easing as _, Linear};
 pub use instant::Duration;
 
+use crate::animations::{easing::Easing, spring::Spring};
+
 /// Configuration for tween-based animations
 ///
+/// Not `Copy`, as `Easing::Linear` holds its points on the heap; clone it instead.
+///
 /// # Examples
 /// ```rust
 /// use dioxus_motion::Duration;
@@ -16,12 +20,12 @@ pub use instant::Duration;
 /// let tween = Tween::new(Duration::from_secs(1))
 ///     .with_easing(easer::functions::Cubic::ease_in_out);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Tween {
     /// Duration of the animation
     pub duration: Duration,
-    /// Easing function for interpolation
-    pub easing: fn(f32, f32, f32, f32) -> f32,
+    /// Easing curve for interpolation
+    pub easing: Easing,
 }
 
 /// Default tween configuration with 300ms duration and linear easing
@@ -29,7 +33,7 @@ impl Default for Tween {
     fn default() -> Self {
         Self {
             duration: Duration::from_millis(300),
-            easing: Linear::ease_in_out,
+            easing: Easing::Function(Linear::ease_in_out),
         }
     }
 }
@@ -39,7 +43,7 @@ impl Tween {
     pub fn new(duration: Duration) -> Self {
         Self {
             duration,
-            easing: Linear::ease_in_out,
+            easing: Easing::Function(Linear::ease_in_out),
         }
     }
 
@@ -48,6 +52,20 @@ impl Tween {
     /// # Arguments
     /// * `easing` - Function that takes (t, b, c, d) and returns interpolated value
     pub fn with_easing(mut self, easing: fn(f32, f32, f32, f32) -> f32) -> Self {
+        self.easing = Easing::Function(easing);
+        self
+    }
+
+    /// Fixed-duration tween moving like `spring`, lasting its settling time
+    pub fn spring(spring: Spring) -> Self {
+        Self {
+            duration: spring.settling_time(),
+            easing: Easing::spring(spring),
+        }
+    }
+
+    /// Sets a CSS-style timing curve such as `cubic-bezier()`, `steps()` or `linear()`
+    pub fn with_timing(mut self, easing: Easing) -> Self {
         self.easing = easing;
         self
     }
@@ -56,13 +74,13 @@ impl Tween {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use easer::functions::{Cubic, Easing};
+    use easer::functions::Cubic;
 
     #[test]
     fn test_tween_new() {
         let tween = Tween {
             duration: Duration::from_secs(1),
-            easing: Cubic::ease_in_out,
+            easing: Easing::Function(Cubic::ease_in_out),
         };
 
         assert_eq!(tween.duration, Duration::from_secs(1));
@@ -72,20 +90,27 @@ mod tests {
     fn test_tween_interpolation() {
         let tween = Tween {
             duration: Duration::from_secs(1),
-            easing: Linear::ease_in_out,
+            easing: Easing::Function(Linear::ease_in_out),
         };
 
         // Test midpoint
         let progress = 0.5;
-        let result = (tween.easing)(progress, 0.0, 1.0, 1.0);
+        let result = tween.easing.apply(progress);
         assert!((result - 0.5).abs() < f32::EPSILON);
 
         // Test start
-        let result = (tween.easing)(0.0, 0.0, 1.0, 1.0);
+        let result = tween.easing.apply(0.0);
         assert!((result - 0.0).abs() < f32::EPSILON);
 
         // Test end
-        let result = (tween.easing)(1.0, 0.0, 1.0, 1.0);
+        let result = tween.easing.apply(1.0);
         assert!((result - 1.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_tween_timing() {
+        let tween = Tween::default().with_timing(Easing::steps(2, Default::default()));
+        assert_eq!(tween.easing.apply(0.6), 0.5);
+        assert_eq!(Tween::default(), Tween::new(Duration::from_millis(300)));
+    }
 }
@@ -6,7 +6,7 @@
 
 use std::sync::{Arc, Mutex};
 
-use crate::animations::{spring::Spring, tween::Tween};
+use crate::animations::{decay::Decay, settings::ReducedMotion, spring::Spring, tween::Tween};
 use instant::Duration;
 
 /// A trait for types that can be animated
@@ -35,15 +35,29 @@ pub trait Animatable: Copy + 'static {
 
     /// Interpolates between self and target using t (0.0 to 1.0)
     fn interpolate(&self, target: &Self, t: f32) -> Self;
+
+    /// The value as a single coordinate, `None` for multi-dimensional types
+    ///
+    /// Lets decay animations apply their `min`/`max` bounds.
+    fn to_scalar(&self) -> Option<f32> {
+        None
+    }
+
+    /// Builds a value from a single coordinate, `None` for multi-dimensional types
+    fn from_scalar(_value: f32) -> Option<Self> {
+        None
+    }
 }
 
 /// Defines the type of animation to be used
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AnimationMode {
     /// Tween animation with duration and easing
     Tween(Tween),
     /// Physics-based spring animation
     Spring(Spring),
+    /// Inertia gliding from a starting velocity to a computed resting point
+    Decay(Decay),
 }
 
 impl Default for AnimationMode {
@@ -61,13 +75,17 @@ pub enum LoopMode {
     Infinite,
     /// Loop animation a specific number of times
     Times(u8),
+    /// Play forward then backward indefinitely (yoyo)
+    Alternate,
+    /// Alternate direction for a specific number of passes, each direction counting as one
+    AlternateTimes(u8),
 }
 
 pub type OnComplete = Arc<Mutex<dyn FnMut() + Send + 'static>>;
 /// Configuration for an animation
 #[derive(Clone, Default)]
 pub struct AnimationConfig {
-    /// The type of animation (Tween or Spring)
+    /// The type of animation (Tween, Spring or Decay)
     pub mode: AnimationMode,
     /// How the animation should loop
     pub loop_mode: Option<LoopMode>,
@@ -75,6 +93,8 @@ pub struct AnimationConfig {
     pub delay: Duration,
     /// Callback when animation completes
     pub on_complete: Option<Arc<Mutex<dyn FnMut() + Send>>>,
+    /// Reduced motion policy overriding the one from `MotionSettings`
+    pub reduced_motion: Option<ReducedMotion>,
 }
 
 impl AnimationConfig {
@@ -85,6 +105,7 @@ impl AnimationConfig {
             loop_mode: None,
             delay: Duration::default(),
             on_complete: None,
+            reduced_motion: None,
         }
     }
 
@@ -109,18 +130,27 @@ impl AnimationConfig {
         self
     }
 
+    /// Sets whether this animation is reduced when reduced motion is requested
+    pub fn with_reduced_motion(mut self, policy: ReducedMotion) -> Self {
+        self.reduced_motion = Some(policy);
+        self
+    }
+
     /// Gets the total duration of the animation
     pub fn get_duration(&self) -> Duration {
         match &self.mode {
-            AnimationMode::Spring(_) => {
-                // Springs don't have a fixed duration, estimate based on typical settling time
-                Duration::from_secs_f32(1.0) // You might want to adjust this based on spring parameters
-            }
+            // Springs don't have a fixed duration, estimate when they settle
+            AnimationMode::Spring(spring) => spring.settling_time(),
+            AnimationMode::Decay(decay) => decay.settling_time(),
             AnimationMode::Tween(tween) => {
                 let base_duration = tween.duration;
                 match self.loop_mode {
-                    Some(LoopMode::Infinite) => Duration::from_secs(f32::INFINITY as u64),
-                    Some(LoopMode::Times(count)) => base_duration * count.into(),
+                    Some(LoopMode::Infinite) | Some(LoopMode::Alternate) => {
+                        Duration::from_secs(f32::INFINITY as u64)
+                    }
+                    Some(LoopMode::Times(count)) | Some(LoopMode::AlternateTimes(count)) => {
+                        base_duration * count.into()
+                    }
                     Some(LoopMode::None) | None => base_duration,
                 }
             }
@@ -136,3 +166,88 @@ impl AnimationConfig {
         }
     }
 }
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::prelude::{Color, Transform};
+    use dioxus_motion_transitions_macro::Animatable;
+    use std::f32::consts::PI;
+
+    fn ease_halfway(from: &f32, to: &f32, _t: f32) -> f32 {
+        from + (to - from) * 0.5
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Animatable)]
+    struct Petal {
+        #[animatable(angle)]
+        rotate: f32,
+        scale: f32,
+        color: Color,
+        #[animatable(with = ease_halfway)]
+        offset: f32,
+        #[animatable(skip)]
+        index: u8,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Animatable)]
+    struct Pair(f32, Transform);
+
+    #[test]
+    fn test_derive_arithmetic() {
+        let a = Petal {
+            rotate: 1.0,
+            scale: 2.0,
+            color: Color::new(0.2, 0.2, 0.2, 1.0),
+            offset: 4.0,
+            index: 3,
+        };
+        let b = a.scale(0.5);
+        assert_eq!(b.scale, 1.0);
+        assert_eq!(b.offset, 2.0);
+        assert_eq!(b.index, 3);
+
+        let sum = a.add(&b);
+        assert_eq!(sum.scale, 3.0);
+        assert_eq!(a.sub(&a).scale, 0.0);
+        assert_eq!(Petal::zero().index, 0);
+        assert_eq!(Petal::epsilon(), Color::epsilon());
+    }
+
+    #[test]
+    fn test_derive_interpolate() {
+        let from = Petal {
+            rotate: PI - 0.1,
+            scale: 0.0,
+            color: Color::new(0.0, 0.0, 0.0, 1.0),
+            offset: 0.0,
+            index: 0,
+        };
+        let to = Petal {
+            rotate: -PI + 0.1,
+            scale: 1.0,
+            color: Color::new(1.0, 1.0, 1.0, 1.0),
+            offset: 10.0,
+            index: 1,
+        };
+
+        let mid = from.interpolate(&to, 0.5);
+        // Shortest path crosses PI instead of sweeping back through zero
+        assert!((mid.rotate.abs() - PI).abs() < 1e-5);
+        assert_eq!(mid.scale, 0.5);
+        assert!((mid.color.r - 0.5).abs() < f32::EPSILON);
+        assert_eq!(mid.offset, 5.0);
+        assert_eq!(mid.index, 0);
+        assert_eq!(from.interpolate(&to, 1.0).index, 1);
+    }
+
+    #[test]
+    fn test_derive_tuple_struct() {
+        let a = Pair(3.0, Transform::new(4.0, 0.0, 0.0, 0.0));
+        assert!((a.magnitude() - 5.0).abs() < f32::EPSILON);
+
+        let mid = Pair::zero().interpolate(&a, 0.5);
+        assert_eq!(mid.0, 1.5);
+        assert_eq!(mid.1.x, 2.0);
+    }
+}
@@ -0,0 +1,560 @@
+//! Drag gesture
+//!
+//! `use_drag` moves an existing motion with the pointer. The drag can be locked to
+//! an axis, pulled elastically past its bounds, and on release keeps its momentum
+//! while a spring settles it inside the bounds or onto the nearest snap point.
+
+use std::marker::PhantomData;
+
+use dioxus::prelude::*;
+use instant::{Duration, Instant};
+use smallvec::SmallVec;
+
+use crate::animations::spring::Spring;
+use crate::animations::transform::Transform;
+use crate::animations::utils::{Animatable, AnimationConfig, AnimationMode};
+use crate::{use_clock, AnimationManager, Clock, TimeProvider};
+
+// Pointer samples older than this don't count towards the release velocity
+const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+// Distance the pointer travels before the direction lock picks an axis
+const DIRECTION_LOCK_THRESHOLD: f32 = 3.0;
+
+/// A value with a 2D position that can be dragged
+pub trait Draggable: Animatable {
+    /// Position moved by the pointer
+    fn position(&self) -> (f32, f32);
+
+    /// Copy of the value moved to `(x, y)`, other components unchanged
+    fn with_position(&self, x: f32, y: f32) -> Self;
+}
+
+impl Draggable for Transform {
+    fn position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    fn with_position(&self, x: f32, y: f32) -> Self {
+        Self { x, y, ..*self }
+    }
+}
+
+/// Follows the horizontal pointer movement
+impl Draggable for f32 {
+    fn position(&self) -> (f32, f32) {
+        (*self, 0.0)
+    }
+
+    fn with_position(&self, x: f32, _y: f32) -> Self {
+        x
+    }
+}
+
+/// Axes the pointer is allowed to move the value along
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DragAxis {
+    #[default]
+    Both,
+    X,
+    Y,
+}
+
+impl DragAxis {
+    fn mask(self, (x, y): (f32, f32)) -> (f32, f32) {
+        match self {
+            DragAxis::Both => (x, y),
+            DragAxis::X => (x, 0.0),
+            DragAxis::Y => (0.0, y),
+        }
+    }
+}
+
+/// Area the dragged position settles in
+///
+/// Unset sides are unbounded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DragBounds {
+    pub min_x: f32,
+    pub max_x: f32,
+    pub min_y: f32,
+    pub max_y: f32,
+}
+
+impl Default for DragBounds {
+    fn default() -> Self {
+        Self {
+            min_x: f32::NEG_INFINITY,
+            max_x: f32::INFINITY,
+            min_y: f32::NEG_INFINITY,
+            max_y: f32::INFINITY,
+        }
+    }
+}
+
+impl DragBounds {
+    pub fn new(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Self {
+        Self {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
+
+    /// Bounds on the x axis only
+    pub fn horizontal(min: f32, max: f32) -> Self {
+        Self {
+            min_x: min,
+            max_x: max,
+            ..Default::default()
+        }
+    }
+
+    /// Bounds on the y axis only
+    pub fn vertical(min: f32, max: f32) -> Self {
+        Self {
+            min_y: min,
+            max_y: max,
+            ..Default::default()
+        }
+    }
+
+    /// Closest position inside the bounds
+    pub fn clamp(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        (
+            x.max(self.min_x).min(self.max_x),
+            y.max(self.min_y).min(self.max_y),
+        )
+    }
+
+    // Lets the position go past the bounds, scaled down by `elastic`
+    fn elastic(&self, position: (f32, f32), elastic: f32) -> (f32, f32) {
+        let (x, y) = self.clamp(position);
+        (
+            x + (position.0 - x) * elastic,
+            y + (position.1 - y) * elastic,
+        )
+    }
+}
+
+/// Configuration for [`use_drag`]
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+///
+/// let config = DragConfig::new()
+///     .with_axis(DragAxis::X)
+///     .with_bounds(DragBounds::horizontal(-200.0, 0.0))
+///     .with_snap_points([(-200.0, 0.0), (0.0, 0.0)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragConfig {
+    pub axis: DragAxis,
+    /// Locks onto the first axis the pointer moves along (only with `DragAxis::Both`)
+    pub direction_lock: bool,
+    pub bounds: Option<DragBounds>,
+    /// Resistance past the bounds: 0.0 stops at the edge, 1.0 follows the pointer (default: 0.35)
+    pub elastic: f32,
+    /// Keeps moving after release (default: true)
+    pub momentum: bool,
+    /// Scales the release velocity (default: 0.8)
+    pub power: f32,
+    /// How long the momentum carries on, in seconds (default: 0.35)
+    pub time_constant: f32,
+    /// Positions the value settles on after release, the nearest one wins
+    pub snap_points: Vec<(f32, f32)>,
+    /// Spring settling the value after release
+    pub spring: Spring,
+}
+
+impl Default for DragConfig {
+    fn default() -> Self {
+        Self {
+            axis: DragAxis::Both,
+            direction_lock: false,
+            bounds: None,
+            elastic: 0.35,
+            momentum: true,
+            power: 0.8,
+            time_constant: 0.35,
+            snap_points: Vec::new(),
+            spring: Spring {
+                stiffness: 300.0,
+                damping: 30.0,
+                mass: 1.0,
+                velocity: 0.0,
+            },
+        }
+    }
+}
+
+impl DragConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_axis(mut self, axis: DragAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn with_direction_lock(mut self) -> Self {
+        self.direction_lock = true;
+        self
+    }
+
+    pub fn with_bounds(mut self, bounds: DragBounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    pub fn with_elastic(mut self, elastic: f32) -> Self {
+        self.elastic = elastic.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_momentum(mut self, momentum: bool) -> Self {
+        self.momentum = momentum;
+        self
+    }
+
+    pub fn with_power(mut self, power: f32) -> Self {
+        self.power = power;
+        self
+    }
+
+    pub fn with_time_constant(mut self, time_constant: f32) -> Self {
+        self.time_constant = time_constant;
+        self
+    }
+
+    pub fn with_snap_points(mut self, points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        self.snap_points = points.into_iter().collect();
+        self
+    }
+
+    pub fn with_spring(mut self, spring: Spring) -> Self {
+        self.spring = spring;
+        self
+    }
+
+    // Nearest snap point on the dragged axes, or the position clamped into the bounds
+    fn settle(&self, position: (f32, f32)) -> (f32, f32) {
+        let distance = |point: &(f32, f32)| {
+            let (dx, dy) = self.axis.mask((point.0 - position.0, point.1 - position.1));
+            dx * dx + dy * dy
+        };
+        let snapped = self
+            .snap_points
+            .iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+            .map(|point| match self.axis {
+                DragAxis::Both => *point,
+                DragAxis::X => (point.0, position.1),
+                DragAxis::Y => (position.0, point.1),
+            });
+
+        snapped.unwrap_or_else(|| {
+            self.bounds
+                .map_or(position, |bounds| bounds.clamp(position))
+        })
+    }
+}
+
+// Where the value goes after release
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Release {
+    target: (f32, f32),
+    velocity: (f32, f32),
+}
+
+#[derive(Debug, Clone, Default)]
+struct DragState {
+    active: bool,
+    pointer_origin: (f32, f32),
+    value_origin: (f32, f32),
+    locked: Option<DragAxis>,
+    position: (f32, f32),
+    samples: SmallVec<[(Instant, (f32, f32)); 8]>,
+}
+
+impl DragState {
+    fn press(&mut self, pointer: (f32, f32), value: (f32, f32), now: Instant) {
+        *self = Self {
+            active: true,
+            pointer_origin: pointer,
+            value_origin: value,
+            position: value,
+            ..Default::default()
+        };
+        self.samples.push((now, value));
+    }
+
+    fn drag(&mut self, config: &DragConfig, pointer: (f32, f32), now: Instant) -> (f32, f32) {
+        let delta = (
+            pointer.0 - self.pointer_origin.0,
+            pointer.1 - self.pointer_origin.1,
+        );
+        // Nothing moves until the direction lock has picked an axis
+        let (dx, dy) = self
+            .axis(config, delta)
+            .map_or((0.0, 0.0), |axis| axis.mask(delta));
+        let raw = (self.value_origin.0 + dx, self.value_origin.1 + dy);
+
+        self.position = config
+            .bounds
+            .map_or(raw, |bounds| bounds.elastic(raw, config.elastic));
+        self.samples
+            .retain(|(time, _)| now.duration_since(*time) <= VELOCITY_WINDOW);
+        self.samples.push((now, self.position));
+        self.position
+    }
+
+    fn axis(&mut self, config: &DragConfig, delta: (f32, f32)) -> Option<DragAxis> {
+        if !config.direction_lock || config.axis != DragAxis::Both {
+            return Some(config.axis);
+        }
+        if self.locked.is_none() && delta.0.abs().max(delta.1.abs()) >= DIRECTION_LOCK_THRESHOLD {
+            self.locked = Some(if delta.0.abs() >= delta.1.abs() {
+                DragAxis::X
+            } else {
+                DragAxis::Y
+            });
+        }
+        self.locked
+    }
+
+    fn velocity(&self, now: Instant) -> (f32, f32) {
+        let (Some(first), Some(last)) = (self.samples.first(), self.samples.last()) else {
+            return (0.0, 0.0);
+        };
+        let span = last.0.duration_since(first.0).as_secs_f32();
+        // A pointer held still before release has no momentum left
+        if span <= 0.0 || now.duration_since(last.0) > VELOCITY_WINDOW {
+            return (0.0, 0.0);
+        }
+        (
+            (last.1 .0 - first.1 .0) / span,
+            (last.1 .1 - first.1 .1) / span,
+        )
+    }
+
+    fn release(&mut self, config: &DragConfig, now: Instant) -> Release {
+        self.active = false;
+
+        let velocity = if config.momentum {
+            let (vx, vy) = self.velocity(now);
+            (vx * config.power, vy * config.power)
+        } else {
+            (0.0, 0.0)
+        };
+        // An exponentially decaying glide travels `velocity * time_constant`
+        let projected = (
+            self.position.0 + velocity.0 * config.time_constant,
+            self.position.1 + velocity.1 * config.time_constant,
+        );
+
+        Release {
+            target: config.settle(projected),
+            velocity,
+        }
+    }
+}
+
+/// Drag gesture bound to a motion, returned by [`use_drag`]
+#[derive(Clone, Copy)]
+pub struct Drag<T: Draggable, M: AnimationManager<T>> {
+    motion: M,
+    config: Signal<DragConfig>,
+    state: Signal<DragState>,
+    clock: CopyValue<Clock>,
+    _value: PhantomData<T>,
+}
+
+impl<T: Draggable, M: AnimationManager<T>> Drag<T, M> {
+    /// Grabs the value at the pointer position, stopping any running animation
+    pub fn start(&mut self, x: f32, y: f32) {
+        let value = self.motion.get_value();
+        self.motion.set_value(value);
+        self.state
+            .write()
+            .press((x, y), value.position(), self.clock.read().now());
+    }
+
+    /// Moves the value with the pointer
+    pub fn update(&mut self, x: f32, y: f32) {
+        if !self.state.peek().active {
+            return;
+        }
+        let (px, py) =
+            self.state
+                .write()
+                .drag(&self.config.peek(), (x, y), self.clock.read().now());
+        let value = self.motion.get_value().with_position(px, py);
+        self.motion.set_value(value);
+    }
+
+    /// Releases the value, handing its momentum off to the settling spring
+    pub fn end(&mut self) {
+        if !self.state.peek().active {
+            return;
+        }
+        let config = self.config.peek();
+        let release = self.state.write().release(&config, self.clock.read().now());
+
+        let (x, y) = release.target;
+        let (vx, vy) = release.velocity;
+        self.motion.animate_to_with_velocity(
+            self.motion.get_value().with_position(x, y),
+            AnimationConfig::new(AnimationMode::Spring(config.spring)),
+            T::zero().with_position(vx, vy),
+        );
+    }
+
+    pub fn on_pointer_down(&mut self, event: Event<PointerData>) {
+        let point = event.client_coordinates();
+        self.start(point.x as f32, point.y as f32);
+    }
+
+    pub fn on_pointer_move(&mut self, event: Event<PointerData>) {
+        let point = event.client_coordinates();
+        self.update(point.x as f32, point.y as f32);
+    }
+
+    pub fn on_pointer_up(&mut self, _event: Event<PointerData>) {
+        self.end();
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.state.read().active
+    }
+
+    pub fn value(&self) -> T {
+        self.motion.get_value()
+    }
+}
+
+/// Makes a motion draggable with the pointer
+///
+/// The config is read on the first render. Pointer moves are only delivered while
+/// the pointer is over the element, so attach `onpointermove` / `onpointerup` to a
+/// container covering the drag area when the element can be outrun.
+///
+/// # Examples
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn Card() -> Element {
+///     let mut drag = use_drag(
+///         use_motion(Transform::identity()),
+///         DragConfig::new().with_bounds(DragBounds::new(-100.0, -100.0, 100.0, 100.0)),
+///     );
+///     let value = drag.value();
+///
+///     rsx! {
+///         div {
+///             style: "transform: translate({value.x}px, {value.y}px); touch-action: none;",
+///             onpointerdown: move |e| drag.on_pointer_down(e),
+///             onpointermove: move |e| drag.on_pointer_move(e),
+///             onpointerup: move |e| drag.on_pointer_up(e),
+///         }
+///     }
+/// }
+/// ```
+pub fn use_drag<T: Draggable, M: AnimationManager<T>>(motion: M, config: DragConfig) -> Drag<T, M> {
+    let config = use_signal(|| config);
+    let state = use_signal(DragState::default);
+    let clock = use_clock();
+    let clock = use_hook(|| CopyValue::new(clock));
+
+    Drag {
+        motion,
+        config,
+        state,
+        clock,
+        _value: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(origin: Instant, ms: u64) -> Instant {
+        origin + Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_drag_axis() {
+        let now = Instant::now();
+        let config = DragConfig::new().with_axis(DragAxis::X);
+        let mut state = DragState::default();
+        state.press((10.0, 10.0), (0.0, 0.0), now);
+        assert_eq!(state.drag(&config, (30.0, 50.0), at(now, 16)), (20.0, 0.0));
+    }
+
+    #[test]
+    fn test_drag_direction_lock() {
+        let now = Instant::now();
+        let config = DragConfig::new().with_direction_lock();
+        let mut state = DragState::default();
+        state.press((0.0, 0.0), (0.0, 0.0), now);
+
+        assert_eq!(state.drag(&config, (1.0, 2.0), at(now, 8)), (0.0, 0.0));
+        assert_eq!(state.drag(&config, (2.0, 6.0), at(now, 16)), (0.0, 6.0));
+        // Stays locked even when the pointer turns
+        assert_eq!(state.drag(&config, (40.0, 8.0), at(now, 24)), (0.0, 8.0));
+    }
+
+    #[test]
+    fn test_drag_elastic_bounds() {
+        let now = Instant::now();
+        let config = DragConfig::new()
+            .with_bounds(DragBounds::horizontal(0.0, 100.0))
+            .with_elastic(0.5)
+            .with_momentum(false);
+        let mut state = DragState::default();
+        state.press((0.0, 0.0), (50.0, 0.0), now);
+
+        // 100px past the edge only shows half of it
+        assert_eq!(state.drag(&config, (150.0, 0.0), at(now, 16)), (150.0, 0.0));
+        let release = state.release(&config, at(now, 32));
+        assert_eq!(release.target, (100.0, 0.0));
+        assert_eq!(release.velocity, (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_release_momentum_snaps() {
+        let now = Instant::now();
+        let config = DragConfig::new()
+            .with_axis(DragAxis::X)
+            .with_power(1.0)
+            .with_time_constant(0.5)
+            .with_snap_points([(0.0, 0.0), (300.0, 0.0)]);
+        let mut state = DragState::default();
+        state.press((0.0, 0.0), (0.0, 0.0), now);
+        state.drag(&config, (50.0, 0.0), at(now, 50));
+        state.drag(&config, (100.0, 0.0), at(now, 100));
+
+        // 1000px/s carries the value 500px further, past the halfway point
+        let release = state.release(&config, at(now, 100));
+        assert!((release.velocity.0 - 1000.0).abs() < 1.0);
+        assert_eq!(release.target, (300.0, 0.0));
+        assert!(!state.active);
+    }
+
+    #[test]
+    fn test_release_after_hold_has_no_momentum() {
+        let now = Instant::now();
+        let config = DragConfig::new().with_snap_points([(0.0, 0.0), (300.0, 0.0)]);
+        let mut state = DragState::default();
+        state.press((0.0, 0.0), (0.0, 0.0), now);
+        state.drag(&config, (100.0, 0.0), at(now, 50));
+
+        let release = state.release(&config, at(now, 400));
+        assert_eq!(release.velocity, (0.0, 0.0));
+        assert_eq!(release.target, (0.0, 0.0));
+    }
+}
@@ -0,0 +1,6 @@
+//! Gesture module
+//!
+//! Ties pointer events to motions: dragging with axis locking, elastic bounds and
+//! momentum that hands off to a spring.
+
+pub mod drag;
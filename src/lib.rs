@@ -1,15 +1,26 @@
 //! Dioxus Motion - Animation library for Dioxus
 //!
 //! Provides smooth animations for web and native applications built with Dioxus.
-//! Supports both spring physics and tween-based animations with configurable parameters.
+//! Supports spring physics, tween-based and decay (inertia) animations with configurable parameters.
 //!
 //! # Features
 //! - Spring physics animations
 //! - Tween animations with custom easing
+//! - Decay animations for flings, with bounce at bounds
 //! - Color interpolation
 //! - Transform animations
 //! - Configurable animation loops
 //! - Animation sequences
+//! - Keyframe tracks with per-segment easing
+//! - Timelines orchestrating several motions
+//! - Staggered animation of motion lists
+//! - Enter and exit animations with `AnimatePresence`
+//! - Layout (FLIP) animations for position and size changes
+//! - Drag gestures with momentum, bounds and snap points
+//! - `#[derive(Animatable)]` for custom types (`derive` feature)
+//! - Global or scoped time scale, pause and frame stepping with `MotionSettings`
+//! - Reduced motion support, following `prefers-reduced-motion` on web
+//! - Deterministic, frame-by-frame testing of motions with [testing::MotionHarness]
 //!
 //! # Example
 //! ```rust,no_run
@@ -39,30 +50,61 @@ use dioxus::prelude::*;
 pub use instant::Duration;
 
 pub mod animations;
+pub mod gestures;
+pub mod testing;
 pub mod transitions;
 
-#[cfg(feature = "transitions")]
+// Lets `#[derive(Animatable)]` resolve `::dioxus_motion` from inside this crate
+extern crate self as dioxus_motion;
+
+#[cfg(any(feature = "transitions", feature = "derive"))]
 pub use dioxus_motion_transitions_macro;
 
-pub use animations::platform::{MotionTime, TimeProvider};
+use animations::decay::Decay;
+use animations::driver::use_frame_ticker;
+use animations::keyframes::Keyframes;
+pub use animations::platform::{use_clock, Clock, MotionTime, TimeProvider};
+use animations::settings::use_motion_settings;
 use animations::spring::{Spring, SpringState};
 use prelude::{AnimationConfig, LoopMode, Transform, Tween};
 use smallvec::SmallVec;
 
 // Re-exports
 pub mod prelude {
-    pub use crate::animations::utils::{AnimationConfig, AnimationMode, LoopMode};
+    pub use crate::animations::utils::{Animatable, AnimationConfig, AnimationMode, LoopMode};
     pub use crate::animations::{
-        colors::Color, spring::Spring, transform::Transform, tween::Tween,
+        colors::Color,
+        decay::Decay,
+        easing::{CubicBezier, Easing, StepPosition},
+        keyframes::Keyframes,
+        layout::{
+            use_layout_animation, use_shared_layout, LayoutAnimation, LayoutMotion, LayoutRect,
+            LayoutTransform,
+        },
+        settings::{
+            use_motion_settings, use_motion_settings_provider, MotionSettings, ReducedMotion,
+        },
+        spring::Spring,
+        stagger::{stagger, use_motion_list, MotionList, Stagger, StaggerFrom},
+        timeline::{use_timeline, Timeline, TimelinePosition},
+        transform::Transform,
+        tween::Tween,
     };
+    #[cfg(feature = "derive")]
+    pub use crate::dioxus_motion_transitions_macro::Animatable;
     #[cfg(feature = "transitions")]
     pub use crate::dioxus_motion_transitions_macro::MotionTransitions;
+    pub use crate::gestures::drag::{use_drag, Drag, DragAxis, DragBounds, DragConfig, Draggable};
     #[cfg(feature = "transitions")]
-    pub use crate::transitions::page_transitions::{AnimatableRoute, AnimatedOutlet};
+    pub use crate::transitions::page_transitions::{
+        AnimatableRoute, AnimatedOutlet, NavigationDirection,
+    };
+    pub use crate::transitions::presence::{AnimatePresence, PresenceState};
     #[cfg(feature = "transitions")]
-    pub use crate::transitions::utils::TransitionVariant;
+    pub use crate::transitions::utils::{PageEffects, TransitionConfig, TransitionVariant};
     pub use crate::{
-        use_motion, AnimationManager, AnimationSequence, Duration, Time, TimeProvider,
+        use_clock, use_motion, use_motion_with_clock, AnimationManager, AnimationSequence, Clock,
+        Duration, MotionTime, Time, TimeProvider,
     };
 }
 
@@ -83,6 +125,8 @@ type AnimationSteps<T> = SmallVec<[AnimationStep<T>; 8]>;
 pub struct AnimationSequence<T: Animatable> {
     steps: AnimationSteps<T>,
     current_step: u8,
+    // Value the sequence started from, used when seeking back into the first step
+    origin: Option<T>,
     on_complete: Option<Box<dyn FnOnce()>>,
     // Add capacity hint for better allocation
     capacity_hint: u8,
@@ -93,6 +137,7 @@ impl<T: Animatable> Clone for AnimationSequence<T> {
         Self {
             steps: self.steps.clone(),
             current_step: self.current_step,
+            origin: self.origin,
             on_complete: None,
             capacity_hint: self.capacity_hint,
         }
@@ -108,6 +153,7 @@ impl<T: Animatable> AnimationSequence<T> {
         Self {
             steps: SmallVec::with_capacity(capacity as usize),
             current_step: 0,
+            origin: None,
             on_complete: None,
             capacity_hint: capacity,
         }
@@ -152,6 +198,22 @@ impl<T: Animatable> AnimationSequence<T> {
         }
         self
     }
+
+    /// Value a step starts from: the previous step's target, or the sequence origin
+    fn origin_of(&self, step: usize) -> Option<T> {
+        step.checked_sub(1).map_or(self.origin, |previous| {
+            self.steps.get(previous).map(|s| s.target)
+        })
+    }
+
+    /// Nominal duration of each step, springs use their estimated settling time
+    fn step_durations(&self) -> impl Iterator<Item = f32> + '_ {
+        self.steps.iter().map(|step| {
+            AnimationConfig::new(step.config.mode.clone())
+                .get_duration()
+                .as_secs_f32()
+        })
+    }
 }
 
 impl<T: Animatable> Default for AnimationSequence<T> {
@@ -159,6 +221,7 @@ impl<T: Animatable> Default for AnimationSequence<T> {
         Self {
             steps: AnimationSteps::new(),
             current_step: 0,
+            origin: None,
             on_complete: None,
             capacity_hint: 0,
         }
@@ -175,8 +238,12 @@ pub struct Motion<T: Animatable> {
     running: bool,
     elapsed: Duration,
     delay_elapsed: Duration,
+    skipped_dt: f32,
     current_loop: u8,
+    paused: bool,
+    reversed: bool,
     sequence: Option<Arc<AnimationSequence<T>>>,
+    keyframes: Option<Arc<Keyframes<T>>>,
 }
 
 impl<T: Animatable> Motion<T> {
@@ -190,36 +257,323 @@ impl<T: Animatable> Motion<T> {
             running: false,
             elapsed: Duration::default(),
             delay_elapsed: Duration::default(),
+            skipped_dt: 0.0,
             current_loop: 0,
+            paused: false,
+            reversed: false,
             sequence: None,
+            keyframes: None,
         }
     }
 
+    /// Animates towards `target`
+    ///
+    /// Retargeting a running animation keeps its current velocity, so a spring
+    /// interrupted mid-flight bends towards the new target instead of stopping dead.
+    /// A spring's `velocity` is added as an initial push towards the target.
+    ///
+    /// A decay ignores `target` as a destination and comes to rest wherever its
+    /// velocity carries it; for multi-dimensional values `target` sets the direction.
     pub fn animate_to(&mut self, target: T, config: AnimationConfig) {
+        let retained = if self.running {
+            self.velocity
+        } else {
+            T::zero()
+        };
+        let push = Self::initial_push(self.current, target, &config);
+        self.animate_to_with_velocity(target, config, retained.add(&push));
+    }
+
+    /// Animates towards `target` starting with an explicit `velocity` (units per second)
+    ///
+    /// Useful to hand off a gesture's release velocity to a spring.
+    pub fn animate_to_with_velocity(&mut self, target: T, config: AnimationConfig, velocity: T) {
         self.sequence = None;
+        self.keyframes = None;
+        self.start_with_velocity(target, config, velocity);
+    }
+
+    // Initial velocity from the spring or decay config
+    fn initial_push(from: T, target: T, config: &AnimationConfig) -> T {
+        let velocity = match config.mode {
+            AnimationMode::Spring(spring) => spring.velocity,
+            AnimationMode::Decay(decay) => match T::from_scalar(decay.velocity) {
+                // One-dimensional values take the velocity with its sign
+                Some(velocity) => return velocity,
+                None => decay.velocity,
+            },
+            AnimationMode::Tween(_) => return T::zero(),
+        };
+
+        // Otherwise the velocity points from `from` towards `target`
+        let delta = target.sub(&from);
+        let distance = delta.magnitude();
+        if velocity == 0.0 || distance <= T::epsilon() {
+            T::zero()
+        } else {
+            delta.scale(velocity / distance)
+        }
+    }
+
+    // Starts a single animation while keeping any sequence or keyframes in place
+    fn start(&mut self, target: T, config: AnimationConfig) {
+        let velocity = Self::initial_push(self.current, target, &config);
+        self.start_with_velocity(target, config, velocity);
+    }
+
+    fn start_with_velocity(&mut self, target: T, config: AnimationConfig, velocity: T) {
         self.initial = self.current;
         self.target = target;
         self.config = Arc::new(config);
         self.running = true;
+        self.paused = false;
+        self.reversed = false;
         self.elapsed = Duration::default();
         self.delay_elapsed = Duration::default();
-        self.velocity = T::zero();
+        self.skipped_dt = 0.0;
+        self.velocity = velocity;
         self.current_loop = 0;
+
+        if let AnimationMode::Decay(decay) = self.config.mode {
+            self.start_decay(decay);
+        }
+    }
+
+    // Computes where the decay comes to rest from the starting velocity
+    fn start_decay(&mut self, decay: Decay) {
+        if let Some(bound) = self.decay_bound(&decay) {
+            // Starting out of bounds springs straight back in
+            self.bounce(decay, bound);
+            return;
+        }
+        self.target = self
+            .current
+            .add(&self.velocity.scale(decay.power * decay.time_constant));
     }
 
-    pub fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
+    // Bound the current value has run past, for one-dimensional values
+    fn decay_bound(&self, decay: &Decay) -> Option<T> {
+        let bound = decay.crossed_bound(self.current.to_scalar()?)?;
+        T::from_scalar(bound)
+    }
+
+    // Hands a decay that reached `bound` over to its bounce spring, keeping the velocity
+    fn bounce(&mut self, decay: Decay, bound: T) {
+        let mut config = (*self.config).clone();
+        config.mode = AnimationMode::Spring(decay.bounce);
+        config.delay = Duration::default();
+        let velocity = self.velocity;
+        self.start_with_velocity(bound, config, velocity);
+    }
+
+    pub fn animate_sequence(&mut self, mut sequence: AnimationSequence<T>) {
         if let Some(first_step) = sequence.steps.first() {
             self.animate_to(first_step.target, (*first_step.config).clone());
+            sequence.current_step = 0;
+            sequence.origin = Some(self.current);
             self.sequence = Some(sequence.into());
         }
     }
 
+    // Moves the sequence to `step`, keeping its completion callback when possible
+    fn set_sequence_step(&mut self, step: u8) {
+        if let Some(sequence) = self.sequence.as_mut() {
+            if let Some(sequence) = Arc::get_mut(sequence) {
+                sequence.current_step = step;
+                return;
+            }
+            let mut new_sequence = (**sequence).clone();
+            new_sequence.current_step = step;
+            *sequence = Arc::new(new_sequence);
+        }
+    }
+
+    /// Plays a keyframe track as a single tween over its duration
+    ///
+    /// The config's loop mode, delay and completion callback apply to the whole
+    /// track; its mode is replaced by the track's duration. Without a keyframe at
+    /// 0.0 the track starts from the current value.
+    pub fn animate_keyframes(&mut self, keyframes: Keyframes<T>, mut config: AnimationConfig) {
+        let keyframes = keyframes.normalized(self.current);
+        let (Some(first), Some(last)) = (keyframes.frames().first(), keyframes.frames().last())
+        else {
+            return;
+        };
+        let (first, last) = (first.value, last.value);
+
+        config.mode = AnimationMode::Tween(Tween::new(keyframes.duration()));
+        self.animate_to(last, config);
+        self.initial = first;
+        self.current = first;
+        self.keyframes = Some(Arc::new(keyframes));
+    }
+
     pub fn value(&self) -> T {
         self.current
     }
 
+    /// Current velocity in units per second
+    pub fn velocity(&self) -> T {
+        self.velocity
+    }
+
+    /// Progress of the current tween or keyframe track (0.0-1.0)
+    ///
+    /// Springs have no fixed duration and report 1.0 once settled, 0.0 before.
+    pub fn progress(&self) -> f32 {
+        match &self.config.mode {
+            AnimationMode::Tween(tween) => {
+                let duration_secs = tween.duration.as_secs_f32();
+                if duration_secs == 0.0 {
+                    1.0
+                } else {
+                    (self.elapsed.as_secs_f32() / duration_secs).min(1.0)
+                }
+            }
+            AnimationMode::Spring(_) => {
+                if self.running {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            AnimationMode::Decay(decay) => {
+                if !self.running || decay.time_constant <= 0.0 {
+                    1.0
+                } else {
+                    // Share of the glide already travelled
+                    1.0 - (-self.elapsed.as_secs_f32() / decay.time_constant).exp()
+                }
+            }
+        }
+    }
+
+    /// Whether an animation is playing; paused animations are not running
     pub fn is_running(&self) -> bool {
-        self.running || self.sequence.is_some()
+        !self.paused && (self.running || self.sequence.is_some())
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Freezes the animation, keeping elapsed time, velocity, delay and sequence position
+    pub fn pause(&mut self) {
+        if self.running || self.sequence.is_some() {
+            self.paused = true;
+        }
+    }
+
+    /// Continues a paused animation exactly where it left off
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Plays the current tween, spring or sequence backwards from where it is now
+    ///
+    /// Calling it again flips back to forward playback. Sequences walk their steps in
+    /// reverse order back to the value they started from. Springs keep their velocity.
+    /// A decay has no path back and is left untouched.
+    pub fn reverse(&mut self) {
+        if matches!(self.config.mode, AnimationMode::Decay(_)) {
+            return;
+        }
+        self.reversed = !self.reversed;
+        if let AnimationMode::Tween(tween) = &self.config.mode {
+            self.elapsed = tween.duration.saturating_sub(self.elapsed);
+        }
+        self.delay_elapsed = self.config.delay;
+        self.running = true;
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Jumps to `progress` (0.0-1.0) of the current tween, keyframe track or sequence
+    ///
+    /// Sequences seek across all of their steps, using the estimated settling time
+    /// for spring and decay steps. A lone spring or decay has no fixed timeline and is
+    /// left untouched.
+    /// Seeking skips any remaining delay and keeps the paused state, so a paused
+    /// motion can be scrubbed directly.
+    pub fn seek(&mut self, progress: f32) {
+        let progress = progress.clamp(0.0, 1.0);
+
+        if let Some(sequence) = self.sequence.clone() {
+            self.seek_sequence(&sequence, progress);
+        } else if let AnimationMode::Tween(Tween { duration, .. }) = self.config.mode {
+            self.seek_tween(duration, progress);
+        }
+    }
+
+    fn seek_tween(&mut self, duration: Duration, progress: f32) {
+        // `progress` is a position on the forward track, map it onto the playback direction
+        let playback = if self.reversed {
+            1.0 - progress
+        } else {
+            progress
+        };
+        self.running = true;
+        self.delay_elapsed = self.config.delay;
+        self.elapsed = duration.mul_f32(playback);
+        self.velocity = T::zero();
+        self.current = self.tween_value(playback);
+    }
+
+    fn seek_sequence(&mut self, sequence: &AnimationSequence<T>, progress: f32) {
+        let durations: SmallVec<[f32; 8]> = sequence.step_durations().collect();
+        let total: f32 = durations.iter().sum();
+        let mut remaining = progress * total;
+
+        // Find the step containing the seek position and the progress within it
+        let mut step_index = durations.len().saturating_sub(1);
+        let mut local_progress = 1.0;
+        for (index, duration) in durations.iter().enumerate() {
+            if remaining <= *duration || index == durations.len() - 1 {
+                step_index = index;
+                local_progress = if *duration > 0.0 {
+                    (remaining / duration).min(1.0)
+                } else {
+                    1.0
+                };
+                break;
+            }
+            remaining -= duration;
+        }
+
+        let Some(step) = sequence.steps.get(step_index) else {
+            return;
+        };
+        let origin = sequence.origin_of(step_index).unwrap_or(self.initial);
+
+        let paused = self.paused;
+        self.set_sequence_step(step_index as u8);
+        self.current = origin;
+        self.start(step.target, (*step.config).clone());
+        self.paused = paused;
+        self.delay_elapsed = self.config.delay;
+
+        match &step.config.mode {
+            AnimationMode::Tween(tween) => self.seek_tween(tween.duration, local_progress),
+            AnimationMode::Spring(_) | AnimationMode::Decay(_) => {
+                // Springs have no closed timeline here, so replay them up to the seek point
+                let seconds = local_progress * durations[step_index];
+                let mut simulation = Motion::new(origin);
+                simulation.animate_to(step.target, AnimationConfig::new(step.config.mode.clone()));
+
+                const STEP: f32 = 1.0 / 120.0;
+                let mut simulated = 0.0;
+                while simulated + STEP <= seconds && simulation.running {
+                    simulation.update(STEP);
+                    simulated += STEP;
+                }
+
+                self.current = simulation.current;
+                self.velocity = simulation.velocity;
+                self.elapsed = Duration::from_secs_f32(seconds);
+            }
+        }
     }
 
     pub fn reset(&mut self) {
@@ -230,11 +584,56 @@ impl<T: Animatable> Motion<T> {
 
     pub fn stop(&mut self) {
         self.running = false;
+        self.paused = false;
         self.current_loop = 0;
         self.velocity = T::zero();
         self.sequence = None;
     }
 
+    /// Jumps straight to `value`, stopping any running animation
+    pub fn set_value(&mut self, value: T) {
+        self.stop();
+        self.current = value;
+        self.target = value;
+    }
+
+    /// Jumps to the end of the animation, and of its sequence, as if it had completed
+    ///
+    /// Looping animations stop at the end of their current iteration.
+    pub fn finish(&mut self) {
+        if !self.running && self.sequence.is_none() {
+            return;
+        }
+
+        let sequence = self.sequence.take();
+        let end = match &sequence {
+            Some(sequence) if self.reversed => sequence.origin_of(0),
+            Some(sequence) => sequence.steps.last().map(|step| step.target),
+            None => None,
+        };
+        self.current = end.unwrap_or_else(|| self.destination());
+        if let AnimationMode::Decay(decay) = self.config.mode {
+            // A decay flung past its bounds comes back to rest on them
+            if let Some(bound) = self.decay_bound(&decay) {
+                self.current = bound;
+            }
+        }
+        self.target = self.current;
+        self.stop();
+
+        if let Some(ref f) = self.config.on_complete {
+            if let Ok(mut guard) = f.lock() {
+                guard();
+            }
+        }
+        if let Some(on_complete) = sequence
+            .and_then(Arc::into_inner)
+            .and_then(|sequence| sequence.on_complete)
+        {
+            on_complete();
+        }
+    }
+
     pub fn delay(&mut self, duration: Duration) {
         let mut config = (*self.config).clone();
         config.delay = duration;
@@ -242,30 +641,50 @@ impl<T: Animatable> Motion<T> {
     }
 
     fn update(&mut self, dt: f32) -> bool {
+        if self.paused {
+            return false;
+        }
+
         if !self.running && self.sequence.is_none() {
             return false;
         }
 
         // Handle sequence if present
         if let Some(sequence) = &mut self.sequence {
-            if !self.running {
+            if !self.running && self.reversed {
+                // Walk back through the previous step, ending at the sequence origin
+                let previous = sequence.current_step.checked_sub(1).and_then(|step| {
+                    let origin = sequence.origin_of(step as usize)?;
+                    let config = (*sequence.steps[step as usize].config).clone();
+                    Some((step, origin, sequence.steps[step as usize].target, config))
+                });
+
+                let Some((step, origin, target, config)) = previous else {
+                    self.sequence = None;
+                    self.stop();
+                    return false;
+                };
+                self.set_sequence_step(step);
+                self.start(target, config);
+                self.initial = origin;
+                self.reversed = true;
+            } else if !self.running {
                 let current_step = sequence.current_step;
                 let total_steps = sequence.steps.len();
 
                 match current_step.cmp(&(total_steps as u8 - 1)) {
                     std::cmp::Ordering::Less => {
-                        let mut new_sequence = (**sequence).clone();
-                        new_sequence.current_step += 1;
-                        let step = &new_sequence.steps[new_sequence.current_step as usize];
+                        let next_step = current_step + 1;
+                        let step = &sequence.steps[next_step as usize];
                         let target = step.target;
                         let config = (*step.config).clone();
-                        let _ = sequence;
-                        self.sequence = Some(Arc::new(new_sequence));
-                        self.animate_to(target, config);
+                        self.set_sequence_step(next_step);
+                        self.start(target, config);
                     }
                     std::cmp::Ordering::Equal => {
-                        let mut sequence_clone = (**sequence).clone();
-                        if let Some(on_complete) = sequence_clone.on_complete.take() {
+                        if let Some(on_complete) =
+                            Arc::get_mut(sequence).and_then(|s| s.on_complete.take())
+                        {
                             on_complete();
                         }
                         self.sequence = None;
@@ -277,9 +696,12 @@ impl<T: Animatable> Motion<T> {
             }
         }
 
-        // Skip updates for imperceptible changes
+        // Batch imperceptible changes, carrying the skipped time to the next frame so
+        // slowed down motions still move
         const MIN_DELTA: f32 = 1.0 / 240.0; // ~4ms
+        let dt = dt + std::mem::take(&mut self.skipped_dt);
         if dt < MIN_DELTA {
+            self.skipped_dt = dt;
             return true;
         }
 
@@ -293,7 +715,8 @@ impl<T: Animatable> Motion<T> {
                 let spring_result = self.update_spring(spring, dt);
                 matches!(spring_result, SpringState::Completed)
             }
-            AnimationMode::Tween(tween) => self.update_tween(tween, dt),
+            AnimationMode::Tween(Tween { duration, .. }) => self.update_tween(duration, dt),
+            AnimationMode::Decay(decay) => self.update_decay(decay, dt),
         };
 
         if completed {
@@ -303,110 +726,20 @@ impl<T: Animatable> Motion<T> {
         }
     }
 
-    #[cfg(feature = "web")]
-    fn update_spring(&mut self, spring: Spring, dt: f32) -> SpringState {
-        const VELOCITY_THRESHOLD: f32 = 0.001;
-        const POSITION_THRESHOLD: f32 = 0.001;
-
-        // Cache frequently accessed values
-        let stiffness = spring.stiffness;
-        let damping = spring.damping;
-        let mass_inv = 1.0 / spring.mass;
-
-        // Use fixed timestep for better stability
-        const FIXED_DT: f32 = 1.0 / 120.0;
-        let steps = ((dt / FIXED_DT) as usize).max(1);
-        let step_dt = dt / steps as f32;
-
-        for _ in 0..steps {
-            let delta = self.target.sub(&self.current);
-
-            // Early exit if movement is negligible
-            if delta.magnitude() < POSITION_THRESHOLD
-                && self.velocity.magnitude() < VELOCITY_THRESHOLD
-            {
-                self.current = self.target;
-                self.velocity = T::zero();
-                return SpringState::Completed;
-            }
-
-            let force = delta.scale(stiffness);
-            let damping_force = self.velocity.scale(damping);
-
-            // Fused multiply-add for better performance
-            self.velocity = self
-                .velocity
-                .add(&(force.sub(&damping_force)).scale(mass_inv * step_dt));
-            self.current = self.current.add(&self.velocity.scale(step_dt));
-        }
-
-        self.check_spring_completion()
-    }
-
-    #[cfg(not(feature = "web"))]
     fn update_spring(&mut self, spring: Spring, dt: f32) -> SpringState {
-        // RK4 integration for better accuracy
-        let stiffness = spring.stiffness;
-        let damping = spring.damping;
-        let mass_inv = 1.0 / spring.mass;
-
-        // State vector: [position, velocity]
-        struct State<T> {
-            pos: T,
-            vel: T,
-        }
-
-        // Compute derivatives for RK4
-        let derive = |state: &State<T>| -> State<T> {
-            let delta = self.target.sub(&state.pos);
-            let force = delta.scale(stiffness);
-            let damping_force = state.vel.scale(damping);
-            let acc = (force.sub(&damping_force)).scale(mass_inv);
-
-            State {
-                pos: state.vel.clone(),
-                vel: acc,
-            }
-        };
-
-        let mut state = State {
-            pos: self.current.clone(),
-            vel: self.velocity.clone(),
-        };
-
-        // Perform RK4 integration
-        let k1 = derive(&state);
-        let k2 = derive(&State {
-            pos: state.pos.add(&k1.pos.scale(dt * 0.5)),
-            vel: state.vel.add(&k1.vel.scale(dt * 0.5)),
-        });
-        let k3 = derive(&State {
-            pos: state.pos.add(&k2.pos.scale(dt * 0.5)),
-            vel: state.vel.add(&k2.vel.scale(dt * 0.5)),
-        });
-        let k4 = derive(&State {
-            pos: state.pos.add(&k3.pos.scale(dt)),
-            vel: state.vel.add(&k3.vel.scale(dt)),
-        });
-
-        const SIXTH: f32 = 1.0 / 6.0;
-
-        // Update position and velocity
-        self.current = state.pos.add(
-            &(k1.pos
-                .add(&k2.pos.scale(2.0))
-                .add(&k3.pos.scale(2.0))
-                .add(&k4.pos))
-            .scale(dt * SIXTH),
-        );
-
-        self.velocity = state.vel.add(
-            &(k1.vel
-                .add(&k2.vel.scale(2.0))
-                .add(&k3.vel.scale(2.0))
-                .add(&k4.vel))
-            .scale(dt * SIXTH),
+        // Closed-form step: exact for any `dt` and identical on every platform
+        let target = self.destination();
+        let displacement = self.current.sub(&target);
+        let response = spring.response(dt);
+
+        self.current = target.add(
+            &displacement
+                .scale(response.position.0)
+                .add(&self.velocity.scale(response.position.1)),
         );
+        self.velocity = displacement
+            .scale(response.velocity.0)
+            .add(&self.velocity.scale(response.velocity.1));
 
         self.check_spring_completion()
     }
@@ -418,11 +751,12 @@ impl<T: Animatable> Motion<T> {
         const EPSILON_SQ: f32 = EPSILON * EPSILON;
 
         let velocity_sq = self.velocity.magnitude().powi(2);
-        let delta = self.target.sub(&self.current);
+        let target = self.destination();
+        let delta = target.sub(&self.current);
         let delta_sq = delta.magnitude().powi(2);
 
         if velocity_sq < EPSILON_SQ && delta_sq < EPSILON_SQ {
-            self.current = self.target;
+            self.current = target;
             self.velocity = T::zero();
             SpringState::Completed
         } else {
@@ -430,39 +764,96 @@ impl<T: Animatable> Motion<T> {
         }
     }
 
-    fn update_tween(&mut self, tween: Tween, dt: f32) -> bool {
+    fn update_tween(&mut self, duration: Duration, dt: f32) -> bool {
         // Use raw float operations instead of Duration for better performance
         let elapsed_secs = self.elapsed.as_secs_f32() + dt;
         self.elapsed = Duration::from_secs_f32(elapsed_secs);
 
         // Avoid division by caching duration reciprocal
-        let duration_secs = tween.duration.as_secs_f32();
+        let duration_secs = duration.as_secs_f32();
         let progress = if duration_secs == 0.0 {
             1.0
         } else {
             (elapsed_secs * (1.0 / duration_secs)).min(1.0)
         };
 
+        let previous = self.current;
+        self.current = self.tween_value(progress);
+        // Track velocity so a tween can hand off smoothly to a spring
+        if dt > 0.0 {
+            self.velocity = self.current.sub(&previous).scale(1.0 / dt);
+        }
+        progress >= 1.0
+    }
+
+    fn update_decay(&mut self, decay: Decay, dt: f32) -> bool {
+        let elapsed_secs = self.elapsed.as_secs_f32() + dt;
+        self.elapsed = Duration::from_secs_f32(elapsed_secs);
+
+        let destination = self.destination();
+        if decay.time_constant <= 0.0 {
+            self.current = destination;
+            self.velocity = T::zero();
+            return true;
+        }
+
+        // Exponential glide: the distance left shrinks by e^(-t / time_constant)
+        let origin = if self.reversed {
+            self.target
+        } else {
+            self.initial
+        };
+        let remaining = destination
+            .sub(&origin)
+            .scale((-elapsed_secs / decay.time_constant).exp());
+        self.current = destination.sub(&remaining);
+        self.velocity = remaining.scale(1.0 / decay.time_constant);
+
+        if let Some(bound) = self.decay_bound(&decay) {
+            self.bounce(decay, bound);
+            return false;
+        }
+
+        if remaining.magnitude() < decay.rest_delta {
+            self.current = destination;
+            self.velocity = T::zero();
+            return true;
+        }
+        false
+    }
+
+    // Value of the current tween or keyframe track at playback `progress`
+    fn tween_value(&self, progress: f32) -> T {
+        // Reversed playback walks the same eased curve backwards
+        let progress = if self.reversed {
+            1.0 - progress
+        } else {
+            progress
+        };
+
         // Skip interpolation if we're at the start or end
         if progress <= 0.0 {
-            self.current = self.initial;
-            return false;
+            return self.initial;
         } else if progress >= 1.0 {
-            self.current = self.target;
-            return true;
+            return self.target;
+        }
+
+        if let Some(keyframes) = &self.keyframes {
+            return keyframes.value_at(progress).unwrap_or(self.current);
         }
 
         // Cache easing result and avoid unnecessary parameters
-        let eased_progress = (tween.easing)(progress, 0.0, 1.0, 1.0);
+        let eased_progress = match &self.config.mode {
+            AnimationMode::Tween(tween) => tween.easing.apply(progress),
+            _ => progress,
+        };
 
         // Fast path for common cases
         match eased_progress {
-            0.0 => self.current = self.initial,
-            1.0 => self.current = self.target,
-            _ => self.current = self.initial.interpolate(&self.target, eased_progress),
+            0.0 => self.initial,
+            1.0 => self.target,
+            _ => self.initial.interpolate(&self.target, eased_progress),
         }
-
-        progress >= 1.0
     }
 
     fn handle_completion(&mut self) -> bool {
@@ -472,9 +863,7 @@ impl<T: Animatable> Motion<T> {
                 false
             }
             LoopMode::Infinite => {
-                self.current = self.initial;
-                self.elapsed = Duration::default();
-                self.velocity = T::zero();
+                self.restart_iteration();
                 true
             }
             LoopMode::Times(count) => {
@@ -483,9 +872,21 @@ impl<T: Animatable> Motion<T> {
                     self.stop();
                     false
                 } else {
-                    self.current = self.initial;
-                    self.elapsed = Duration::default();
-                    self.velocity = T::zero();
+                    self.restart_iteration();
+                    true
+                }
+            }
+            LoopMode::Alternate => {
+                self.alternate_iteration();
+                true
+            }
+            LoopMode::AlternateTimes(count) => {
+                self.current_loop += 1;
+                if self.current_loop >= count {
+                    self.stop();
+                    false
+                } else {
+                    self.alternate_iteration();
                     true
                 }
             }
@@ -502,12 +903,43 @@ impl<T: Animatable> Motion<T> {
         should_continue
     }
 
+    // Jumps back to where the playback started and plays again in the same direction
+    fn restart_iteration(&mut self) {
+        self.current = if self.reversed {
+            self.target
+        } else {
+            self.initial
+        };
+        self.elapsed = Duration::default();
+        self.velocity = T::zero();
+    }
+
+    // Plays the next iteration in the opposite direction, starting where the last one ended
+    fn alternate_iteration(&mut self) {
+        self.reversed = !self.reversed;
+        self.elapsed = Duration::default();
+        self.velocity = T::zero();
+    }
+
+    // Value the animation is currently heading towards
+    fn destination(&self) -> T {
+        if self.reversed {
+            self.initial
+        } else {
+            self.target
+        }
+    }
+
     fn get_value(&self) -> T {
         self.current
     }
 }
 
 /// Combined Animation Manager trait
+///
+/// Methods added after the first release have default implementations, so existing
+/// implementors keep compiling. The defaults give up on velocity, pausing, seeking and
+/// reversing; override them to support those.
 pub trait AnimationManager<T: Animatable>: Clone + Copy {
     fn new(initial: T) -> Self;
     fn animate_to(&mut self, target: T, config: AnimationConfig);
@@ -518,6 +950,61 @@ pub trait AnimationManager<T: Animatable>: Clone + Copy {
     fn reset(&mut self);
     fn stop(&mut self);
     fn delay(&mut self, duration: Duration);
+
+    /// Animates towards `target` from `velocity`; starts at rest by default
+    fn animate_to_with_velocity(&mut self, target: T, config: AnimationConfig, velocity: T) {
+        let _ = velocity;
+        self.animate_to(target, config);
+    }
+
+    /// Plays `keyframes`; goes straight to the last keyframe by default
+    fn animate_keyframes(&mut self, keyframes: Keyframes<T>, config: AnimationConfig) {
+        if let Some(target) = keyframes.value_at(1.0) {
+            let mode = AnimationMode::Tween(Tween::new(keyframes.duration()));
+            self.animate_to(target, AnimationConfig { mode, ..config });
+        }
+    }
+
+    /// Current velocity; zero by default
+    fn get_velocity(&self) -> T {
+        T::zero()
+    }
+
+    /// Progress of the current animation (0.0-1.0); 0.0 while running by default
+    fn get_progress(&self) -> f32 {
+        if self.is_running() {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Jumps to `value`; by default with an instant tween applied on the next update
+    fn set_value(&mut self, value: T) {
+        self.animate_to(
+            value,
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::ZERO))),
+        );
+    }
+
+    /// Freezes the animation; does nothing by default
+    fn pause(&mut self) {}
+
+    /// Continues a paused animation; does nothing by default
+    fn resume(&mut self) {}
+
+    /// Whether the animation is paused; never by default
+    fn is_paused(&self) -> bool {
+        false
+    }
+
+    /// Jumps to `progress` (0.0-1.0) of the animation; does nothing by default
+    fn seek(&mut self, progress: f32) {
+        let _ = progress;
+    }
+
+    /// Plays the animation backwards; does nothing by default
+    fn reverse(&mut self) {}
 }
 
 impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
@@ -529,12 +1016,17 @@ impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
         self.write().animate_to(target, config);
     }
 
+    fn animate_to_with_velocity(&mut self, target: T, config: AnimationConfig, velocity: T) {
+        self.write()
+            .animate_to_with_velocity(target, config, velocity);
+    }
+
     fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
-        if let Some(first_step) = sequence.steps.first() {
-            let mut state = self.write();
-            state.animate_to(first_step.target, (*first_step.config).clone());
-            state.sequence = Some(sequence.into());
-        }
+        self.write().animate_sequence(sequence);
+    }
+
+    fn animate_keyframes(&mut self, keyframes: Keyframes<T>, config: AnimationConfig) {
+        self.write().animate_keyframes(keyframes, config);
     }
 
     fn update(&mut self, dt: f32) -> bool {
@@ -545,6 +1037,14 @@ impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
         self.read().get_value()
     }
 
+    fn get_velocity(&self) -> T {
+        self.read().velocity()
+    }
+
+    fn get_progress(&self) -> f32 {
+        self.read().progress()
+    }
+
     fn is_running(&self) -> bool {
         self.read().is_running()
     }
@@ -557,6 +1057,30 @@ impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
         self.write().stop();
     }
 
+    fn set_value(&mut self, value: T) {
+        self.write().set_value(value);
+    }
+
+    fn pause(&mut self) {
+        self.write().pause();
+    }
+
+    fn resume(&mut self) {
+        self.write().resume();
+    }
+
+    fn is_paused(&self) -> bool {
+        self.read().is_paused()
+    }
+
+    fn seek(&mut self, progress: f32) {
+        self.write().seek(progress);
+    }
+
+    fn reverse(&mut self) {
+        self.write().reverse();
+    }
+
     fn delay(&mut self, duration: Duration) {
         let mut state = self.write();
         let mut config = (*state.config).clone();
@@ -567,10 +1091,10 @@ impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
 
 /// Creates an animation manager that continuously updates a motion state.
 ///
-/// This function initializes a motion state with the provided initial value and spawns an asynchronous loop
-/// that updates the animation state based on the elapsed time between frames. When the animation is running,
-/// it updates the state using the calculated time delta and dynamically adjusts the update interval to optimize CPU usage;
-/// when the animation is inactive, it waits longer before polling again.
+/// This function initializes a motion state with the provided initial value and registers it with the
+/// frame loop of its [Clock], shared by every motion using that clock. While any of them is running,
+/// the loop updates them all once per frame based on the elapsed time; once they have all settled,
+/// it sleeps until an animation starts again.
 ///
 /// # Example
 ///
@@ -596,58 +1120,54 @@ impl<T: Animatable> AnimationManager<T> for Signal<Motion<T>> {
 /// }
 /// ```
 pub fn use_motion<T: Animatable>(initial: T) -> impl AnimationManager<T> {
-    let mut state = use_signal(|| Motion::new(initial));
+    let clock = use_clock();
+    use_motion_state(initial, clock)
+}
 
-    #[cfg(feature = "web")]
-    let idle_poll_rate = Duration::from_millis(100);
-
-    #[cfg(not(feature = "web"))]
-    let idle_poll_rate = Duration::from_millis(33);
-
-    use_effect(move || {
-        // This executes after rendering is complete
-        spawn(async move {
-            let mut last_frame = Time::now();
-            let mut _running_frames = 0u32;
-
-            loop {
-                let now = Time::now();
-                let dt = (now.duration_since(last_frame).as_secs_f32()).min(0.1);
-
-                // Only check if running first, then write to the signal
-                if state.peek().is_running() {
-                    _running_frames += 1;
-                    state.write().update(dt);
-
-                    #[cfg(feature = "web")]
-                    // Adaptive frame rate
-                    let delay = match dt {
-                        x if x < 0.008 => Duration::from_millis(8),  // ~120fps
-                        x if x < 0.016 => Duration::from_millis(16), // ~60fps
-                        _ => Duration::from_millis(32),              // ~30fps
-                    };
-
-                    #[cfg(not(feature = "web"))]
-                    let delay = match _running_frames {
-                        // Higher frame rate for the first ~200 frames for smooth starts
-                        0..=200 => Duration::from_micros(8333), // ~120fps
-                        _ => match dt {
-                            x if x < 0.005 => Duration::from_millis(8),  // ~120fps
-                            x if x < 0.011 => Duration::from_millis(16), // ~60fps
-                            _ => Duration::from_millis(33),              // ~30fps
-                        },
-                    };
-
-                    Time::delay(delay).await;
-                } else {
-                    _running_frames = 0;
-                    Time::delay(idle_poll_rate).await;
-                }
+/// [use_motion] driven by `clock` instead of the [Clock] from the context
+///
+/// # Example
+///
+/// ```no_run
+/// use dioxus_motion::prelude::*;
+/// use dioxus::prelude::*;
+///
+/// fn app() -> Element {
+///     let value = use_motion_with_clock(0.0f32, MotionTime);
+///     rsx! { "{value.get_value()}" }
+/// }
+/// ```
+pub fn use_motion_with_clock<T: Animatable, C: TimeProvider>(
+    initial: T,
+    clock: C,
+) -> impl AnimationManager<T> {
+    let clock = use_hook(|| Clock::new(clock));
+    use_motion_state(initial, clock)
+}
 
-                last_frame = now;
+pub(crate) fn use_motion_state<T: Animatable>(initial: T, clock: Clock) -> Signal<Motion<T>> {
+    let mut state = use_signal(|| Motion::new(initial));
+    let settings = use_motion_settings();
+    let mut seen_steps = settings.peek().steps();
+
+    use_frame_ticker(
+        clock,
+        move || state.read().is_running(),
+        move |dt| {
+            // Only check if running first, then write to the signal
+            if !state.peek().is_running() {
+                return false;
             }
-        });
-    });
+            let settings = *settings.peek();
+            let frame_time = settings.frame_time(dt, &mut seen_steps);
+            if settings.reduces(state.peek().config.reduced_motion) {
+                state.write().finish();
+            } else if frame_time > 0.0 {
+                state.write().update(frame_time);
+            }
+            state.peek().is_running()
+        },
+    );
 
     state
 }
@@ -657,3 +1177,230 @@ thread_local! {
     static TRANSFORM_BUFFER: RefCell<Vec<Transform>> = RefCell::new(Vec::with_capacity(32));
     static SPRING_BUFFER: RefCell<Vec<SpringState>> = RefCell::new(Vec::with_capacity(16));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn linear(ms: u64) -> AnimationConfig {
+        AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(ms))))
+    }
+
+    #[test]
+    fn test_pause_and_resume_keep_progress() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, linear(1000));
+        motion.update(0.25);
+
+        motion.pause();
+        assert!(motion.is_paused());
+        assert!(!motion.is_running());
+        motion.update(0.5);
+        assert_eq!(motion.value(), 25.0);
+
+        motion.resume();
+        motion.update(0.25);
+        assert_eq!(motion.value(), 50.0);
+    }
+
+    #[test]
+    fn test_pause_keeps_spring_velocity() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        motion.update(0.05);
+        let velocity = motion.velocity;
+        assert!(velocity > 0.0);
+
+        motion.pause();
+        motion.resume();
+        assert_eq!(motion.velocity, velocity);
+    }
+
+    #[test]
+    fn test_seek_tween() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, linear(1000).with_delay(Duration::from_secs(1)));
+        motion.pause();
+
+        motion.seek(0.75);
+        assert_eq!(motion.value(), 75.0);
+        assert!((motion.progress() - 0.75).abs() < 1e-6);
+        assert!(motion.is_paused());
+
+        motion.resume();
+        motion.update(0.25);
+        assert_eq!(motion.value(), 100.0);
+    }
+
+    #[test]
+    fn test_sequence_plays_every_step() {
+        let done = Arc::new(AtomicBool::new(false));
+        let flag = done.clone();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, linear(100))
+                .then(20.0, linear(100))
+                .then(30.0, linear(100))
+                .on_complete(move || flag.store(true, Ordering::SeqCst)),
+        );
+
+        for _ in 0..10 {
+            motion.update(0.1);
+        }
+        assert_eq!(motion.value(), 30.0);
+        assert!(!motion.is_running());
+        assert!(done.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_finish_jumps_to_the_end() {
+        let done = Arc::new(AtomicBool::new(false));
+        let flag = done.clone();
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, linear(100))
+                .then(20.0, linear(100).with_loop(LoopMode::Infinite))
+                .on_complete(move || flag.store(true, Ordering::SeqCst)),
+        );
+        motion.update(0.05);
+
+        motion.finish();
+        assert_eq!(motion.value(), 20.0);
+        assert!(!motion.is_running());
+        assert!(done.load(Ordering::SeqCst));
+        assert!(!motion.update(0.1));
+    }
+
+    #[test]
+    fn test_alternate_loop() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, linear(1000).with_loop(LoopMode::AlternateTimes(2)));
+
+        motion.update(1.0);
+        assert_eq!(motion.value(), 100.0);
+        assert!(motion.is_reversed());
+
+        // Backward pass continues from the end instead of snapping to the start
+        motion.update(0.25);
+        assert_eq!(motion.value(), 75.0);
+
+        motion.update(0.75);
+        assert_eq!(motion.value(), 0.0);
+        assert!(!motion.is_running());
+    }
+
+    #[test]
+    fn test_reverse_tween_from_current_position() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, linear(1000));
+        motion.update(0.75);
+
+        motion.reverse();
+        motion.update(0.25);
+        assert_eq!(motion.value(), 50.0);
+
+        motion.update(0.5);
+        assert_eq!(motion.value(), 0.0);
+        assert!(!motion.is_running());
+    }
+
+    #[test]
+    fn test_reverse_sequence() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, linear(1000))
+                .then(30.0, linear(1000)),
+        );
+        motion.update(1.0);
+        motion.update(0.1);
+        motion.update(0.4);
+        assert_eq!(motion.value(), 20.0);
+
+        motion.reverse();
+        motion.update(0.5);
+        assert_eq!(motion.value(), 10.0);
+        motion.update(0.1);
+        motion.update(0.4);
+        assert_eq!(motion.value(), 5.0);
+
+        for _ in 0..10 {
+            motion.update(0.1);
+        }
+        assert_eq!(motion.value(), 0.0);
+        assert!(!motion.is_running());
+    }
+
+    #[test]
+    fn test_spring_independent_of_frame_rate() {
+        let spring = AnimationConfig::new(AnimationMode::Spring(Spring::wobbly()));
+        let mut coarse = Motion::new(0.0f32);
+        let mut fine = Motion::new(0.0f32);
+        coarse.animate_to(100.0, spring.clone());
+        fine.animate_to(100.0, spring);
+
+        coarse.update(0.1);
+        for _ in 0..10 {
+            fine.update(0.01);
+        }
+        assert!((coarse.value() - fine.value()).abs() < 1e-3);
+        assert!((coarse.velocity() - fine.velocity()).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_retarget_keeps_velocity() {
+        let spring = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(100.0, spring.clone());
+        motion.update(0.1);
+        let velocity = motion.velocity();
+        assert!(velocity > 0.0);
+
+        motion.animate_to(-100.0, spring);
+        assert_eq!(motion.velocity(), velocity);
+    }
+
+    #[test]
+    fn test_initial_velocity() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to_with_velocity(
+            0.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+            500.0,
+        );
+        motion.update(0.05);
+        assert!(motion.value() > 0.0);
+
+        let pushed = Spring {
+            velocity: 50.0,
+            ..Default::default()
+        };
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_to(-10.0, AnimationConfig::new(AnimationMode::Spring(pushed)));
+        assert_eq!(motion.velocity(), -50.0);
+    }
+
+    #[test]
+    fn test_seek_sequence() {
+        let mut motion = Motion::new(0.0f32);
+        motion.animate_sequence(
+            AnimationSequence::new()
+                .then(10.0, linear(1000))
+                .then(30.0, linear(1000)),
+        );
+
+        motion.seek(0.75);
+        assert_eq!(motion.value(), 20.0);
+        assert_eq!(motion.sequence.as_ref().map(|s| s.current_step), Some(1));
+
+        motion.seek(0.25);
+        assert_eq!(motion.value(), 5.0);
+        assert_eq!(motion.sequence.as_ref().map(|s| s.current_step), Some(0));
+    }
+}
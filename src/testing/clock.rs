@@ -0,0 +1,128 @@
+//! Virtual clock advanced by hand
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use instant::{Duration, Instant};
+
+use crate::animations::platform::TimeProvider;
+
+struct ClockState {
+    start: Instant,
+    elapsed: Duration,
+    frame: u64,
+    wakers: Vec<Waker>,
+}
+
+/// [TimeProvider] whose time only moves when [ManualClock::advance] is called
+///
+/// Every advance is one frame: the clock moves forward and every pending
+/// [delay](TimeProvider::delay) completes, whatever its duration, like waiting for the
+/// next animation frame. A motion loop therefore ticks exactly once per advance with a
+/// `dt` of the advanced duration.
+///
+/// Clones share the same time.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// use dioxus_motion::testing::ManualClock;
+///
+/// let clock = ManualClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_millis(16));
+/// assert_eq!(clock.now() - start, Duration::from_millis(16));
+/// ```
+#[derive(Clone)]
+pub struct ManualClock {
+    state: Rc<RefCell<ClockState>>,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(ClockState {
+                start: Instant::now(),
+                elapsed: Duration::ZERO,
+                frame: 0,
+                wakers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Moves the clock forward by `duration` and completes every pending delay
+    pub fn advance(&self, duration: Duration) {
+        let wakers = {
+            let mut state = self.state.borrow_mut();
+            state.elapsed += duration;
+            state.frame += 1;
+            std::mem::take(&mut state.wakers)
+        };
+        wakers.into_iter().for_each(Waker::wake);
+    }
+
+    /// Time advanced since the clock was created
+    pub fn elapsed(&self) -> Duration {
+        self.state.borrow().elapsed
+    }
+}
+
+impl TimeProvider for ManualClock {
+    fn now(&self) -> Instant {
+        let state = self.state.borrow();
+        state.start + state.elapsed
+    }
+
+    fn delay(&self, _duration: Duration) -> impl Future<Output = ()> {
+        NextFrame {
+            state: self.state.clone(),
+            frame: self.state.borrow().frame,
+        }
+    }
+}
+
+/// Completes once the clock is advanced past `frame`
+struct NextFrame {
+    state: Rc<RefCell<ClockState>>,
+    frame: u64,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.borrow_mut();
+        if state.frame > self.frame {
+            Poll::Ready(())
+        } else {
+            state.wakers.push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+
+    #[test]
+    fn test_delay_completes_on_next_advance() {
+        let clock = ManualClock::new();
+        let mut delay = Box::pin(clock.delay(Duration::from_secs(10)));
+        assert!(delay.as_mut().now_or_never().is_none());
+
+        clock.clone().advance(Duration::from_millis(16));
+        assert!(delay.now_or_never().is_some());
+        assert_eq!(clock.elapsed(), Duration::from_millis(16));
+    }
+}
@@ -0,0 +1,488 @@
+//! Headless `VirtualDom` running hooks and motions frame by frame
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+use futures_util::FutureExt;
+use instant::Duration;
+
+use crate::animations::settings::MotionSettings;
+use crate::animations::utils::{Animatable, AnimationConfig};
+use crate::{use_clock, use_motion_state, AnimationManager, Clock, Motion};
+
+use super::clock::ManualClock;
+
+#[derive(Clone)]
+struct AppProps {
+    app: Rc<RefCell<dyn FnMut() -> Element>>,
+}
+
+fn app_root(props: AppProps) -> Element {
+    (props.app.borrow_mut())()
+}
+
+/// Renders `app` in a headless [VirtualDom] driven by a [ManualClock]
+///
+/// The clock is provided as the root [Clock]. Nothing moves until the harness is
+/// stepped, and each step is exactly one frame of every motion in the app, so hooks
+/// and components can be asserted at precise points of their animations.
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// use dioxus_motion::testing::AppHarness;
+///
+/// let (mut harness, mut cards) = AppHarness::mount_hook(|| use_motion_list(3, 0.0f32));
+/// harness.update(|| {
+///     cards.animate_all(
+///         1.0,
+///         AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100)))),
+///         &stagger(Duration::from_millis(100), StaggerFrom::First, None),
+///     )
+/// });
+/// harness.advance(Duration::from_millis(150));
+/// // The second card is halfway, the last one hasn't started yet
+/// let values = harness.read(|| cards.values());
+/// assert_eq!(values[0], 1.0);
+/// assert_eq!(values[2], 0.0);
+/// ```
+pub struct AppHarness {
+    dom: VirtualDom,
+    clock: ManualClock,
+    frame: Duration,
+}
+
+impl AppHarness {
+    /// Mounts `app` as the root component, stepped at 60fps
+    pub fn new(app: impl FnMut() -> Element + 'static) -> Self {
+        let clock = ManualClock::new();
+        let app: Rc<RefCell<dyn FnMut() -> Element>> = Rc::new(RefCell::new(app));
+        let mut dom = VirtualDom::new_with_props(app_root, AppProps { app })
+            .with_root_context(Clock::new(clock.clone()));
+        dom.rebuild_in_place();
+
+        let mut harness = Self {
+            dom,
+            clock,
+            frame: Duration::from_secs_f64(1.0 / 60.0),
+        };
+        // Let the frame loop start and wait for its first frame
+        harness.flush();
+        harness
+    }
+
+    /// Mounts a component calling `hook`, returning what it returned on the first render
+    pub fn mount_hook<V: Clone + 'static>(mut hook: impl FnMut() -> V + 'static) -> (Self, V) {
+        let slot = Rc::new(RefCell::new(None));
+        let harness = Self::new({
+            let slot = slot.clone();
+            move || {
+                slot.borrow_mut().get_or_insert(hook());
+                rsx! {}
+            }
+        });
+        let value = slot.take().expect("the harness root renders on mount");
+        (harness, value)
+    }
+
+    /// Duration of each frame stepped by [AppHarness::step]
+    pub fn with_frame_duration(mut self, frame: Duration) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Clock driving the app
+    pub fn clock(&self) -> &ManualClock {
+        &self.clock
+    }
+
+    /// [MotionSettings] applied to the app's motions, changed with `write()` like in an app
+    pub fn settings(&self) -> Signal<MotionSettings> {
+        self.read(|| ScopeId::ROOT.consume_context())
+            .expect("the app's motions register the global settings")
+    }
+
+    /// Applies `f` to the motion settings
+    pub fn update_settings(&mut self, f: impl FnOnce(&mut MotionSettings)) {
+        let mut settings = self.settings();
+        self.update(|| f(&mut settings.write()));
+    }
+
+    /// Runs `f` inside the app, e.g. to read a signal
+    pub fn read<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.dom.in_runtime(f)
+    }
+
+    /// Runs `f` inside the app, e.g. to write a signal or start a motion, then lets
+    /// the app react without advancing the clock
+    pub fn update<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        let result = self.dom.in_runtime(f);
+        self.flush();
+        result
+    }
+
+    /// Advances one frame
+    pub fn step(&mut self) {
+        self.clock.advance(self.frame);
+        self.flush();
+    }
+
+    /// Advances `frames` frames
+    pub fn step_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    /// Advances frame by frame until at least `duration` has passed
+    pub fn advance(&mut self, duration: Duration) {
+        let frames = duration.as_secs_f64() / self.frame.as_secs_f64();
+        self.step_frames(frames.ceil() as usize);
+    }
+
+    /// Runs every task, effect and render that is ready without advancing the clock
+    fn flush(&mut self) {
+        while self.dom.wait_for_work().now_or_never().is_some() {
+            self.dom.render_immediate(&mut NoOpMutations);
+        }
+    }
+}
+
+/// Runs a `use_motion` hook in an [AppHarness]
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+/// use dioxus_motion::testing::MotionHarness;
+///
+/// let mut harness = MotionHarness::new(0.0f32);
+/// harness.animate_to(
+///     100.0,
+///     AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(100)))),
+/// );
+/// harness.advance(Duration::from_millis(200));
+/// assert_eq!(harness.value(), 100.0);
+/// assert!(!harness.is_running());
+/// ```
+pub struct MotionHarness<T: Animatable> {
+    app: AppHarness,
+    motion: Signal<Motion<T>>,
+}
+
+impl<T: Animatable> MotionHarness<T> {
+    /// Mounts a motion starting at `initial`, stepped at 60fps
+    pub fn new(initial: T) -> Self {
+        // Same as `use_motion`, keeping hold of the signal behind it
+        let (app, motion) = AppHarness::mount_hook(move || use_motion_state(initial, use_clock()));
+        Self { app, motion }
+    }
+
+    /// Duration of each frame stepped by [MotionHarness::step]
+    pub fn with_frame_duration(mut self, frame: Duration) -> Self {
+        self.app = self.app.with_frame_duration(frame);
+        self
+    }
+
+    /// Clock driving the motion
+    pub fn clock(&self) -> &ManualClock {
+        self.app.clock()
+    }
+
+    /// [MotionSettings] applied to the motion, changed with `write()` like in an app
+    pub fn settings(&self) -> Signal<MotionSettings> {
+        self.app.settings()
+    }
+
+    /// Applies `f` to the motion settings
+    pub fn update_settings(&mut self, f: impl FnOnce(&mut MotionSettings)) {
+        self.app.update_settings(f);
+    }
+
+    /// Runs `f` on the motion, e.g. to start a sequence or pause it
+    pub fn with_motion<R>(&mut self, f: impl FnOnce(&mut Signal<Motion<T>>) -> R) -> R {
+        let mut motion = self.motion;
+        self.app.update(|| f(&mut motion))
+    }
+
+    pub fn animate_to(&mut self, target: T, config: AnimationConfig) {
+        self.with_motion(|motion| motion.animate_to(target, config));
+    }
+
+    pub fn value(&self) -> T {
+        self.app.read(|| self.motion.get_value())
+    }
+
+    pub fn velocity(&self) -> T {
+        self.app.read(|| self.motion.get_velocity())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.app.read(|| self.motion.is_running())
+    }
+
+    /// Advances one frame
+    pub fn step(&mut self) {
+        self.app.step();
+    }
+
+    /// Advances `frames` frames
+    pub fn step_frames(&mut self, frames: usize) {
+        self.app.step_frames(frames);
+    }
+
+    /// Advances frame by frame until at least `duration` has passed
+    pub fn advance(&mut self, duration: Duration) {
+        self.app.advance(duration);
+    }
+
+    /// Steps until the motion stops, returning the number of frames it took, or `None`
+    /// if it's still running after `max_frames`
+    pub fn run_until_idle(&mut self, max_frames: usize) -> Option<usize> {
+        for frame in 0..=max_frames {
+            if !self.is_running() {
+                return Some(frame);
+            }
+            if frame < max_frames {
+                self.step();
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{AnimationMode, LoopMode, ReducedMotion, Spring, Tween};
+    use crate::AnimationSequence;
+    use std::cell::Cell;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn linear(millis: u64) -> AnimationConfig {
+        AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(
+            millis,
+        ))))
+    }
+
+    fn motion_harness() -> MotionHarness<f32> {
+        MotionHarness::new(0.0f32).with_frame_duration(Duration::from_millis(10))
+    }
+
+    #[test]
+    fn test_tween_frames_are_deterministic() {
+        let mut harness = motion_harness();
+        let completed = Arc::new(AtomicBool::new(false));
+        let flag = completed.clone();
+        harness.animate_to(
+            100.0,
+            linear(100).with_on_complete(move || flag.store(true, Ordering::SeqCst)),
+        );
+        assert_eq!(harness.value(), 0.0);
+
+        harness.step_frames(5);
+        assert!((harness.value() - 50.0).abs() < 0.01);
+        assert!(harness.is_running());
+        assert!(!completed.load(Ordering::SeqCst));
+
+        harness.advance(Duration::from_millis(60));
+        assert_eq!(harness.value(), 100.0);
+        assert!(!harness.is_running());
+        assert!(completed.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_time_scale_and_stepping() {
+        let mut harness = motion_harness();
+        harness.update_settings(|settings| settings.time_scale = 0.5);
+        harness.animate_to(100.0, linear(100));
+        harness.step_frames(10);
+        assert!((harness.value() - 50.0).abs() < 0.01);
+
+        harness.update_settings(|settings| settings.paused = true);
+        harness.step_frames(10);
+        assert!((harness.value() - 50.0).abs() < 0.01);
+
+        // One 60fps frame at a time
+        harness.update_settings(MotionSettings::step);
+        harness.step_frames(10);
+        assert!((harness.value() - 100.0 * (0.05 + 1.0 / 60.0) / 0.1).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_slow_motion_at_60fps() {
+        // Each frame is shorter than the minimum motion update at 10% speed
+        let mut harness = MotionHarness::new(0.0f32);
+        harness.update_settings(|settings| settings.time_scale = 0.1);
+        harness.animate_to(100.0, linear(100));
+        harness.step_frames(30);
+        assert!((harness.value() - 50.0).abs() < 1.0);
+
+        assert!(harness.run_until_idle(40).is_some());
+        assert_eq!(harness.value(), 100.0);
+    }
+
+    #[test]
+    fn test_reduced_motion_jumps_to_the_end() {
+        let mut harness = MotionHarness::new(0.0f32);
+        harness.update_settings(|settings| settings.prefers_reduced_motion = true);
+        harness.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+        harness.step();
+        assert_eq!(harness.value(), 100.0);
+        assert!(!harness.is_running());
+
+        // Animations opting out keep playing
+        harness.animate_to(
+            0.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default()))
+                .with_reduced_motion(ReducedMotion::Never),
+        );
+        harness.step();
+        assert!(harness.is_running());
+    }
+
+    #[test]
+    fn test_frame_driver_sleeps_when_idle() {
+        let mut harness = MotionHarness::new(0.0f32);
+        let driver = harness
+            .app
+            .read(|| ScopeId::ROOT.consume_context::<Clock>())
+            .expect("the harness provides its clock")
+            .driver();
+        assert!(!driver.is_awake());
+
+        harness.animate_to(1.0, linear(50));
+        assert!(driver.is_awake());
+        assert!(harness.run_until_idle(10).is_some());
+        harness.step();
+        assert!(!driver.is_awake());
+
+        // Starting again wakes it up
+        harness.animate_to(0.0, linear(50));
+        assert!(driver.is_awake());
+    }
+
+    #[test]
+    fn test_tween_interpolation() {
+        let mut harness = motion_harness();
+        harness.animate_to(100.0, linear(1000));
+
+        harness.advance(Duration::from_millis(500));
+        assert!((harness.value() - 50.0).abs() < 0.1);
+        assert!(harness.is_running());
+
+        assert!(harness.run_until_idle(60).is_some());
+        assert_eq!(harness.value(), 100.0);
+    }
+
+    #[test]
+    fn test_spring_physics() {
+        let mut harness = motion_harness();
+        harness.animate_to(
+            100.0,
+            AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+        );
+
+        harness.step();
+        assert!(harness.value() > 0.0);
+        assert!(harness.velocity() > 0.0);
+
+        assert!(harness.run_until_idle(1000).is_some());
+        assert_eq!(harness.value(), 100.0);
+        assert_eq!(harness.velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_loop_modes() {
+        let mut harness = motion_harness();
+        let completed = Arc::new(AtomicUsize::new(0));
+        let counter = completed.clone();
+        harness.animate_to(
+            100.0,
+            linear(100)
+                .with_loop(LoopMode::Times(2))
+                .with_on_complete(move || {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }),
+        );
+
+        // Back to the start after the first pass
+        harness.advance(Duration::from_millis(110));
+        assert!(harness.is_running());
+        assert!(harness.value() < 100.0);
+        assert_eq!(completed.load(Ordering::SeqCst), 0);
+
+        assert!(harness.run_until_idle(20).is_some());
+        assert_eq!(harness.value(), 100.0);
+        assert_eq!(completed.load(Ordering::SeqCst), 1);
+
+        // Infinite loops keep running
+        harness.animate_to(0.0, linear(100).with_loop(LoopMode::Infinite));
+        assert!(harness.run_until_idle(100).is_none());
+    }
+
+    #[test]
+    fn test_delay_handling() {
+        let mut harness = motion_harness();
+        harness.animate_to(100.0, linear(100).with_delay(Duration::from_millis(200)));
+
+        harness.advance(Duration::from_millis(200));
+        assert_eq!(harness.value(), 0.0);
+        assert!(harness.is_running());
+
+        harness.advance(Duration::from_millis(50));
+        assert!(harness.value() > 0.0);
+        assert!(harness.run_until_idle(20).is_some());
+        assert_eq!(harness.value(), 100.0);
+    }
+
+    #[test]
+    fn test_animation_sequence() {
+        let mut harness = motion_harness();
+        let completed = Rc::new(Cell::new(false));
+        let flag = completed.clone();
+        harness.with_motion(|motion| {
+            motion.animate_sequence(
+                AnimationSequence::new()
+                    .then(
+                        50.0,
+                        AnimationConfig::new(AnimationMode::Spring(Spring::stiff())),
+                    )
+                    .then(100.0, linear(100))
+                    .on_complete(move || flag.set(true)),
+            )
+        });
+        assert_eq!(harness.value(), 0.0);
+
+        harness.step();
+        assert!(harness.is_running());
+        assert!(harness.value() > 0.0 && harness.value() < 50.0);
+
+        assert!(harness.run_until_idle(1000).is_some());
+        assert_eq!(harness.value(), 100.0);
+        assert!(completed.get());
+    }
+
+    #[test]
+    fn test_stop_and_reset() {
+        let mut harness = motion_harness();
+        harness.animate_to(100.0, linear(100));
+        harness.advance(Duration::from_millis(50));
+
+        harness.with_motion(|motion| motion.stop());
+        let stopped = harness.value();
+        assert!(!harness.is_running());
+        assert_eq!(harness.velocity(), 0.0);
+        harness.step_frames(5);
+        assert_eq!(harness.value(), stopped);
+
+        harness.with_motion(|motion| motion.reset());
+        assert_eq!(harness.value(), 0.0);
+        assert!(!harness.is_running());
+    }
+}
@@ -0,0 +1,12 @@
+//! Testing module
+//!
+//! Helpers to test motions deterministically. [ManualClock] is a virtual clock that
+//! only moves when advanced by hand. [AppHarness] renders hooks or components in a
+//! headless `VirtualDom` on that clock, stepping it frame by frame, and
+//! [MotionHarness] does so for a single `use_motion`.
+
+mod clock;
+mod harness;
+
+pub use clock::ManualClock;
+pub use harness::{AppHarness, MotionHarness};
@@ -1,2 +1,3 @@
 pub mod page_transitions;
+pub mod presence;
 pub mod utils;
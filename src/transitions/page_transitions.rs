@@ -3,16 +3,55 @@ use std::marker::PhantomData;
 use dioxus::prelude::*;
 
 use crate::{
-    prelude::{AnimationConfig, AnimationMode, Spring},
+    prelude::{use_motion_settings, AnimationConfig, Duration, ReducedMotion, Transform},
     use_motion, AnimationManager,
 };
 
-use super::utils::TransitionVariant;
+use super::utils::{PageEffects, TransitionConfig, TransitionVariant};
+
+/// Whether a navigation moves forward or returns to the previous route
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NavigationDirection {
+    #[default]
+    Forward,
+    Back,
+}
+
+/// Routes visited by an [AnimatedOutlet], used to tell back from forward navigation.
+///
+/// Returning to the route before the current one counts as going back, whether it
+/// came from the browser's back button or a link.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteHistory<R: PartialEq> {
+    stack: Vec<R>,
+}
+
+impl<R: PartialEq> RouteHistory<R> {
+    pub fn new(initial: R) -> Self {
+        Self {
+            stack: vec![initial],
+        }
+    }
+
+    /// Record a visit to `to` and tell in which direction it went.
+    pub fn visit(&mut self, to: R) -> NavigationDirection {
+        let len = self.stack.len();
+        if len >= 2 && self.stack[len - 2] == to {
+            self.stack.pop();
+            NavigationDirection::Back
+        } else {
+            if self.stack.last() != Some(&to) {
+                self.stack.push(to);
+            }
+            NavigationDirection::Forward
+        }
+    }
+}
 
 #[derive(Clone)]
 pub enum AnimatedRouterContext<R: Routable + PartialEq> {
     /// Transition from one route to another.
-    FromTo(R, R),
+    FromTo(R, R, NavigationDirection),
     /// Settled in a route.
     In(R),
 }
@@ -21,25 +60,39 @@ impl<R: Routable + PartialEq> AnimatedRouterContext<R> {
     /// Get the current destination route.
     pub fn target_route(&self) -> &R {
         match self {
-            Self::FromTo(_, to) => to,
+            Self::FromTo(_, to, _) => to,
             Self::In(to) => to,
         }
     }
 
+    /// Direction of the current transition, forward once settled.
+    pub fn direction(&self) -> NavigationDirection {
+        match self {
+            Self::FromTo(_, _, direction) => *direction,
+            Self::In(_) => NavigationDirection::Forward,
+        }
+    }
+
     /// Update the destination route.
     pub fn set_target_route(&mut self, to: R) {
+        self.navigate(to, NavigationDirection::Forward)
+    }
+
+    /// Update the destination route, reached by navigating in `direction`.
+    pub fn navigate(&mut self, to: R, direction: NavigationDirection) {
         match self {
-            Self::FromTo(old_from, old_to) => {
+            Self::FromTo(old_from, old_to, old_direction) => {
                 *old_from = old_to.clone();
-                *old_to = to
+                *old_to = to;
+                *old_direction = direction
             }
-            Self::In(old_to) => *self = Self::FromTo(old_to.clone(), to),
+            Self::In(old_to) => *self = Self::FromTo(old_to.clone(), to, direction),
         }
     }
 
     /// After the transition animation has finished, make the outlet only render the destination route.
     pub fn settle(&mut self) {
-        if let Self::FromTo(_, to) = self {
+        if let Self::FromTo(_, to, _) = self {
             *self = Self::In(to.clone())
         }
     }
@@ -57,24 +110,25 @@ pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
     let route = use_route::<R>();
     // Create router context only if we're the root AnimatedOutlet
     let mut prev_route = use_signal(|| AnimatedRouterContext::In(route.clone()));
+    let mut history = use_signal(|| RouteHistory::new(route.clone()));
     use_context_provider(move || prev_route);
 
     use_effect(move || {
-        if prev_route.peek().target_route() != &use_route::<R>() {
-            prev_route
-                .write()
-                .set_target_route(use_route::<R>().clone());
+        let route = use_route::<R>();
+        if prev_route.peek().target_route() != &route {
+            let direction = history.write().visit(route.clone());
+            prev_route.write().navigate(route, direction);
         }
     });
 
     let outlet: OutletContext<R> = use_outlet_context();
 
-    let from_route: Option<(R, R)> = match prev_route() {
-        AnimatedRouterContext::FromTo(from, to) => Some((from, to)),
+    let from_route: Option<(R, R, NavigationDirection)> = match prev_route() {
+        AnimatedRouterContext::FromTo(from, to, direction) => Some((from, to, direction)),
         _ => None,
     };
 
-    if let Some((from, to)) = from_route {
+    if let Some((from, to, direction)) = from_route {
         // Special handling for transitions from root path
         let is_from_root = from.to_string() == "/";
 
@@ -85,6 +139,7 @@ pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
                     route_type: PhantomData,
                     from: from.clone(),
                     to: to.clone(),
+                    direction,
                 }
             };
         } else {
@@ -101,6 +156,14 @@ pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
 
 pub trait AnimatableRoute: Routable + Clone + PartialEq {
     fn get_transition(&self) -> TransitionVariant;
+    /// Transforms and timing of the transition into this route
+    fn get_transition_config(&self) -> TransitionConfig {
+        self.get_transition().get_config()
+    }
+    /// Transition when navigating back from this route, its forward transition reversed by default
+    fn get_back_transition_config(&self) -> TransitionConfig {
+        self.get_transition_config().reversed()
+    }
     fn get_component(&self) -> Element;
     fn get_layout_depth(&self) -> usize;
 }
@@ -111,44 +174,46 @@ pub fn use_animated_router<Route: Routable + PartialEq>() -> Signal<AnimatedRout
 }
 
 #[component]
-fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, to: R) -> Element {
+fn FromRouteToCurrent<R: AnimatableRoute>(
+    route_type: PhantomData<R>,
+    from: R,
+    to: R,
+    direction: NavigationDirection,
+) -> Element {
     let mut animated_router = use_animated_router::<R>();
-    let config = to.get_transition().get_config();
+    let reduced = use_motion_settings().peek().is_reduced();
+    let config = match direction {
+        // A quick cross-fade instead of any movement
+        _ if reduced => TransitionConfig::cross_fade(Duration::from_millis(150)),
+        NavigationDirection::Forward => to.get_transition_config(),
+        NavigationDirection::Back => from.get_back_transition_config(),
+    };
     let mut from_transform = use_motion(config.exit_start);
     let mut to_transform = use_motion(config.enter_start);
-    let mut from_opacity = use_motion(1.0f32);
-    let mut to_opacity = use_motion(0.0f32);
+    let mut from_effects = use_motion(config.exit_start_effects);
+    let mut to_effects = use_motion(config.enter_start_effects);
 
     use_effect(move || {
-        let spring = Spring {
-            stiffness: 160.0, // Reduced from 180.0 for less aggressive movement
-            damping: 25.0,    // Increased from 12.0 for faster settling
-            mass: 1.5,        // Slightly increased for more "weight"
-            velocity: 10.0,   // Keep at 0 for predictable start
-        };
+        // Reduced motion is already handled by the choice of transition
+        let exit = AnimationConfig::new(config.exit_mode.clone())
+            .with_reduced_motion(ReducedMotion::Never);
+        let enter = AnimationConfig::new(config.enter_mode.clone())
+            .with_reduced_motion(ReducedMotion::Never);
 
         // Animate FROM route
-        from_transform.animate_to(
-            config.exit_end,
-            AnimationConfig::new(AnimationMode::Spring(spring)),
-        );
+        from_transform.animate_to(config.exit_end, exit.clone());
+        from_effects.animate_to(config.exit_end_effects, exit);
 
         // Animate TO route
-        to_transform.animate_to(
-            config.enter_end,
-            AnimationConfig::new(AnimationMode::Spring(spring)),
-        );
-
-        // Fade out old route
-        from_opacity.animate_to(0.0, AnimationConfig::new(AnimationMode::Spring(spring)));
-        to_opacity.animate_to(1.0, AnimationConfig::new(AnimationMode::Spring(spring)));
+        to_transform.animate_to(config.enter_end, enter.clone());
+        to_effects.animate_to(config.enter_end_effects, enter);
     });
 
     use_effect(move || {
         if !from_transform.is_running()
             && !to_transform.is_running()
-            && !from_opacity.is_running()
-            && !to_opacity.is_running()
+            && !from_effects.is_running()
+            && !to_effects.is_running()
         {
             animated_router.write().settle();
         }
@@ -157,31 +222,86 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
     rsx! {
         div {
             class: "route-container",
-            style: "position: relative; overflow-visible;",
+            style: "position: relative; overflow-visible; perspective: 1000px;",
             div {
                 class: "route-content from",
-                style: "
-                    transform: translate3d({from_transform.get_value().x}%, {from_transform.get_value().y}%, 0) 
-                             scale({from_transform.get_value().scale});
-                    opacity: {from_opacity.get_value()};
-                    will-change: transform, opacity;
-                       backface-visibility: hidden;
-                    -webkit-backface-visibility: hidden;
-                ",
+                style: page_style(from_transform.get_value(), from_effects.get_value()),
                 {from.render(from.get_layout_depth() + 1)}
             }
             div {
                 class: "route-content to",
-                style: "
-                    transform: translate3d({to_transform.get_value().x}%, {to_transform.get_value().y}%, 0) 
-                             scale({to_transform.get_value().scale});
-                    opacity: {to_opacity.get_value()};
-                    will-change: transform, opacity;
-                    backface-visibility: hidden;
-                    -webkit-backface-visibility: hidden;
-                ",
+                style: page_style(to_transform.get_value(), to_effects.get_value()),
                 Outlet::<R> {}
             }
         }
     }
 }
+
+/// Inline style rendering a page with its transform and effects
+fn page_style(transform: Transform, effects: PageEffects) -> String {
+    let (top, right, bottom, left) = effects.clip_inset;
+    format!(
+        "transform: translate3d({}%, {}%, 0) scale({}) rotate({}rad) rotateX({}rad) rotateY({}rad); \
+         opacity: {}; \
+         filter: blur({}px); \
+         clip-path: inset({}% {}% {}% {}%); \
+         will-change: transform, opacity, filter, clip-path; \
+         backface-visibility: hidden; \
+         -webkit-backface-visibility: hidden;",
+        transform.x,
+        transform.y,
+        transform.scale,
+        transform.rotation,
+        effects.rotate_x,
+        effects.rotate_y,
+        // Springs can overshoot past what CSS accepts
+        effects.opacity.clamp(0.0, 1.0),
+        effects.blur.max(0.0),
+        top,
+        right,
+        bottom,
+        left,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_history_direction() {
+        let mut history = RouteHistory::new("/");
+        assert_eq!(history.visit("/blog"), NavigationDirection::Forward);
+        assert_eq!(history.visit("/blog/post"), NavigationDirection::Forward);
+        assert_eq!(history.visit("/blog"), NavigationDirection::Back);
+        assert_eq!(history.visit("/"), NavigationDirection::Back);
+        // Nothing left to go back to
+        assert_eq!(history.visit("/about"), NavigationDirection::Forward);
+        assert_eq!(history.visit("/about"), NavigationDirection::Forward);
+        assert_eq!(history.visit("/"), NavigationDirection::Back);
+    }
+
+    #[test]
+    fn test_page_style() {
+        let style = page_style(
+            Transform::new(-100.0, 0.0, 1.0, 0.5),
+            PageEffects::hidden()
+                .with_rotate_y(1.0)
+                .with_blur(4.0)
+                .with_clip_inset(0.0, 50.0, 0.0, 0.0),
+        );
+        assert!(style.contains(
+            "translate3d(-100%, 0%, 0) scale(1) rotate(0.5rad) rotateX(0rad) rotateY(1rad)"
+        ));
+        assert!(style.contains("opacity: 0;"));
+        assert!(style.contains("filter: blur(4px);"));
+        assert!(style.contains("clip-path: inset(0% 50% 0% 0%);"));
+
+        let overshot = page_style(
+            Transform::identity(),
+            PageEffects::visible().with_opacity(1.2).with_blur(-0.5),
+        );
+        assert!(overshot.contains("opacity: 1;"));
+        assert!(overshot.contains("filter: blur(0px);"));
+    }
+}
@@ -0,0 +1,224 @@
+//! Presence module
+//!
+//! Dioxus drops an element as soon as it leaves `rsx!`, which leaves no room for an
+//! exit animation. [`AnimatePresence`] keeps its content rendered while it animates
+//! out and only unmounts it once the exit motion has completed.
+
+use dioxus::prelude::*;
+
+use crate::{
+    prelude::{Animatable, AnimationConfig, AnimationMode, Spring},
+    use_motion, AnimationManager,
+};
+
+/// Lifecycle of the content of an [`AnimatePresence`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceState {
+    /// Entering or shown.
+    Present,
+    /// Animating out, still rendered.
+    Exiting,
+    /// Exit finished, no longer rendered.
+    Absent,
+}
+
+impl PresenceState {
+    /// Whether the content should be rendered.
+    pub fn is_rendered(&self) -> bool {
+        *self != Self::Absent
+    }
+
+    /// Show or hide the content right away; returns `false` when nothing changes.
+    ///
+    /// Hiding switches to `Exiting` at once, even while the content is still entering,
+    /// and it stays rendered until [`PresenceState::settle`]. Showing switches back to
+    /// `Present` at once, even during an exit.
+    pub fn set_shown(&mut self, show: bool) -> bool {
+        let next = match (*self, show) {
+            (Self::Absent, false) => return false,
+            (_, true) => Self::Present,
+            (_, false) => Self::Exiting,
+        };
+        *self = next;
+        true
+    }
+
+    /// After the exit animation has finished, stop rendering the content.
+    pub fn settle(&mut self) {
+        if *self == Self::Exiting {
+            *self = Self::Absent
+        }
+    }
+}
+
+/// Animates content in when `show` turns true and out before unmounting it.
+///
+/// The content is produced by `render` from the current animated value: it enters
+/// from `initial` to `animate`, and leaves towards `exit`. Showing it again during
+/// the exit retargets the running motion back to `animate`.
+///
+/// ```rust,no_run
+/// use dioxus::prelude::*;
+/// use dioxus_motion::prelude::*;
+///
+/// fn Toast() -> Element {
+///     let mut open = use_signal(|| true);
+///
+///     rsx! {
+///         button { onclick: move |_| open.toggle(), "Toggle" }
+///         AnimatePresence {
+///             show: open(),
+///             initial: 0.0f32,
+///             animate: 1.0f32,
+///             exit: 0.0f32,
+///             render: move |opacity: f32| rsx! {
+///                 div { style: "opacity: {opacity}", "Saved!" }
+///             },
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn AnimatePresence<T: Animatable + PartialEq>(
+    /// Whether the content should be shown
+    show: bool,
+    /// Value the content enters from
+    initial: T,
+    /// Value while shown
+    animate: T,
+    /// Value the content leaves towards before unmounting
+    exit: T,
+    /// Renders the content from the current value
+    render: Callback<T, Element>,
+    /// Animation used to enter (default: spring)
+    #[props(default = AnimationMode::Spring(Spring::default()))]
+    mode: AnimationMode,
+    /// Animation used to exit, falls back to `mode`
+    #[props(default)]
+    exit_mode: Option<AnimationMode>,
+    /// Called once the content has been unmounted after its exit
+    #[props(default)]
+    on_exit_complete: Option<EventHandler>,
+) -> Element {
+    let mut motion = use_motion(if show { initial } else { exit });
+    let mut state = use_signal(|| {
+        if show {
+            PresenceState::Present
+        } else {
+            PresenceState::Absent
+        }
+    });
+
+    use_effect(use_reactive(
+        (&show, &initial, &animate, &exit, &mode, &exit_mode),
+        move |(show, initial, animate, exit, mode, exit_mode)| {
+            let entering = *state.peek() == PresenceState::Absent;
+            if !state.write().set_shown(show) {
+                return;
+            }
+            if !show {
+                let mode = exit_mode.unwrap_or(mode);
+                motion.animate_to(exit, AnimationConfig::new(mode));
+                return;
+            }
+            if entering {
+                motion.set_value(initial);
+            }
+            motion.animate_to(animate, AnimationConfig::new(mode));
+        },
+    ));
+
+    // Deferred unmount, like `AnimatedRouterContext::settle` for routes
+    use_effect(move || {
+        if state() == PresenceState::Exiting && !motion.is_running() {
+            state.write().settle();
+            if let Some(handler) = on_exit_complete {
+                handler.call(());
+            }
+        }
+    });
+
+    if !state.read().is_rendered() {
+        return rsx! {};
+    }
+    render.call(motion.get_value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Tween;
+    use crate::testing::AppHarness;
+    use crate::Duration;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_presence_exit_then_settle() {
+        let mut state = PresenceState::Present;
+        assert!(state.set_shown(false));
+        assert_eq!(state, PresenceState::Exiting);
+        // Still rendered until the exit motion completes
+        assert!(state.is_rendered());
+
+        state.settle();
+        assert_eq!(state, PresenceState::Absent);
+        assert!(!state.is_rendered());
+        assert!(!state.set_shown(false));
+    }
+
+    #[test]
+    fn test_presence_shown_again_while_exiting() {
+        let mut state = PresenceState::Exiting;
+        assert!(state.set_shown(true));
+        assert_eq!(state, PresenceState::Present);
+        // Settling only applies to an exit
+        state.settle();
+        assert_eq!(state, PresenceState::Present);
+    }
+
+    #[component]
+    fn Content(mounted: Signal<bool>) -> Element {
+        let mut mounted = mounted;
+        use_hook(move || mounted.set(true));
+        use_drop(move || mounted.set(false));
+        rsx! {}
+    }
+
+    #[test]
+    fn test_presence_unmounts_after_exit() {
+        let signals = Rc::new(Cell::new(None));
+        let slot = signals.clone();
+        let mut harness = AppHarness::new(move || {
+            let show = use_signal(|| true);
+            let mounted = use_signal(|| false);
+            let mut exited = use_signal(|| false);
+            slot.set(Some((show, mounted, exited)));
+            rsx! {
+                AnimatePresence {
+                    show: show(),
+                    initial: 0.0f32,
+                    animate: 1.0f32,
+                    exit: 0.0f32,
+                    mode: AnimationMode::Tween(Tween::new(Duration::from_millis(100))),
+                    render: move |_: f32| rsx! { Content { mounted } },
+                    on_exit_complete: move |_| exited.set(true),
+                }
+            }
+        })
+        .with_frame_duration(Duration::from_millis(10));
+        let (mut show, mounted, exited) = signals.get().expect("the app renders on mount");
+        harness.advance(Duration::from_millis(200));
+        assert!(harness.read(|| *mounted.read()));
+
+        harness.update(|| show.set(false));
+        harness.advance(Duration::from_millis(90));
+        // Still rendered while the exit motion runs
+        assert!(harness.read(|| *mounted.read()));
+        assert!(!harness.read(|| *exited.read()));
+
+        harness.advance(Duration::from_millis(30));
+        assert!(!harness.read(|| *mounted.read()));
+        assert!(harness.read(|| *exited.read()));
+    }
+}
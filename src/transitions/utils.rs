@@ -1,6 +1,149 @@
-use crate::prelude::Transform;
+use std::f32::consts::{FRAC_PI_2, PI};
 
-#[derive(Clone)]
+use crate::prelude::{Animatable, AnimationMode, Duration, Spring, Transform, Tween};
+
+/// Visual effects of a page on top of its [Transform]
+///
+/// # Examples
+/// ```rust
+/// use dioxus_motion::prelude::*;
+///
+/// // Blurred, half transparent and clipped to its left half
+/// let effects = PageEffects::visible()
+///     .with_opacity(0.5)
+///     .with_blur(8.0)
+///     .with_clip_inset(0.0, 50.0, 0.0, 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PageEffects {
+    /// Opacity between 0 and 1
+    pub opacity: f32,
+    /// Rotation around the horizontal axis in radians
+    pub rotate_x: f32,
+    /// Rotation around the vertical axis in radians
+    pub rotate_y: f32,
+    /// Blur radius in pixels
+    pub blur: f32,
+    /// Clip-path inset from each edge in percent (top, right, bottom, left)
+    pub clip_inset: (f32, f32, f32, f32),
+}
+
+impl Default for PageEffects {
+    fn default() -> Self {
+        Self::visible()
+    }
+}
+
+impl PageEffects {
+    /// Fully shown page without any effect
+    pub fn visible() -> Self {
+        Self {
+            opacity: 1.0,
+            rotate_x: 0.0,
+            rotate_y: 0.0,
+            blur: 0.0,
+            clip_inset: (0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Fully transparent page
+    pub fn hidden() -> Self {
+        Self::visible().with_opacity(0.0)
+    }
+
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    pub fn with_rotate_x(mut self, radians: f32) -> Self {
+        self.rotate_x = radians;
+        self
+    }
+
+    pub fn with_rotate_y(mut self, radians: f32) -> Self {
+        self.rotate_y = radians;
+        self
+    }
+
+    pub fn with_blur(mut self, pixels: f32) -> Self {
+        self.blur = pixels;
+        self
+    }
+
+    /// Clips the page by the given percentages from its top, right, bottom and left edges
+    pub fn with_clip_inset(mut self, top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        self.clip_inset = (top, right, bottom, left);
+        self
+    }
+}
+
+impl Animatable for PageEffects {
+    fn zero() -> Self {
+        Self {
+            opacity: 0.0,
+            rotate_x: 0.0,
+            rotate_y: 0.0,
+            blur: 0.0,
+            clip_inset: (0.0, 0.0, 0.0, 0.0),
+        }
+    }
+
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    fn magnitude(&self) -> f32 {
+        let (top, right, bottom, left) = self.clip_inset;
+        (self.opacity * self.opacity
+            + self.rotate_x * self.rotate_x
+            + self.rotate_y * self.rotate_y
+            + self.blur * self.blur
+            + top * top
+            + right * right
+            + bottom * bottom
+            + left * left)
+            .sqrt()
+    }
+
+    fn scale(&self, factor: f32) -> Self {
+        let (top, right, bottom, left) = self.clip_inset;
+        Self {
+            opacity: self.opacity * factor,
+            rotate_x: self.rotate_x * factor,
+            rotate_y: self.rotate_y * factor,
+            blur: self.blur * factor,
+            clip_inset: (top * factor, right * factor, bottom * factor, left * factor),
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let (top, right, bottom, left) = self.clip_inset;
+        let (other_top, other_right, other_bottom, other_left) = other.clip_inset;
+        Self {
+            opacity: self.opacity + other.opacity,
+            rotate_x: self.rotate_x + other.rotate_x,
+            rotate_y: self.rotate_y + other.rotate_y,
+            blur: self.blur + other.blur,
+            clip_inset: (
+                top + other_top,
+                right + other_right,
+                bottom + other_bottom,
+                left + other_left,
+            ),
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.scale(-1.0))
+    }
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        self.add(&target.sub(self).scale(t))
+    }
+}
+
+#[derive(Clone, PartialEq)]
 pub struct TransitionConfig {
     // For the page that's leaving (FROM)
     pub exit_start: Transform, // Starting position of exiting page
@@ -9,6 +152,84 @@ pub struct TransitionConfig {
     // For the page that's entering (TO)
     pub enter_start: Transform, // Starting position of entering page
     pub enter_end: Transform,   // Final position of entering page
+
+    // Opacity, 3D rotation, blur and clipping of each page
+    pub exit_start_effects: PageEffects, // Starting effects of exiting page
+    pub exit_end_effects: PageEffects,   // Final effects of exiting page (default: faded out)
+    pub enter_start_effects: PageEffects, // Starting effects of entering page (default: faded out)
+    pub enter_end_effects: PageEffects,  // Final effects of entering page
+
+    // Timing of each page
+    pub exit_mode: AnimationMode,  // Animation of the exiting page
+    pub enter_mode: AnimationMode, // Animation of the entering page
+}
+
+impl Default for TransitionConfig {
+    fn default() -> Self {
+        let spring = AnimationMode::Spring(Spring {
+            stiffness: 160.0, // Reduced from 180.0 for less aggressive movement
+            damping: 25.0,    // Increased from 12.0 for faster settling
+            mass: 1.5,        // Slightly increased for more "weight"
+            velocity: 0.0,    // Keep at 0 for predictable start
+        });
+        let identity = Transform::identity();
+
+        Self {
+            exit_start: identity,
+            exit_end: identity,
+            enter_start: identity,
+            enter_end: identity,
+            // Cross-fade
+            exit_start_effects: PageEffects::visible(),
+            exit_end_effects: PageEffects::hidden(),
+            enter_start_effects: PageEffects::hidden(),
+            enter_end_effects: PageEffects::visible(),
+            exit_mode: spring.clone(),
+            enter_mode: spring,
+        }
+    }
+}
+
+impl TransitionConfig {
+    /// Plain cross-fade lasting `duration`, used when motion is reduced
+    pub fn cross_fade(duration: Duration) -> Self {
+        Self::default().with_mode(AnimationMode::Tween(Tween::new(duration)))
+    }
+
+    /// Uses `mode` to animate both pages
+    pub fn with_mode(mut self, mode: AnimationMode) -> Self {
+        self.exit_mode = mode.clone();
+        self.enter_mode = mode;
+        self
+    }
+
+    pub fn with_exit_mode(mut self, mode: AnimationMode) -> Self {
+        self.exit_mode = mode;
+        self
+    }
+
+    pub fn with_enter_mode(mut self, mode: AnimationMode) -> Self {
+        self.enter_mode = mode;
+        self
+    }
+
+    /// Plays the transition backwards, as when navigating back
+    ///
+    /// The page that entered leaves the way it came and the page that left returns.
+    pub fn reversed(self) -> Self {
+        Self {
+            exit_start: self.enter_end,
+            exit_end: self.enter_start,
+            enter_start: self.exit_end,
+            enter_end: self.exit_start,
+            exit_start_effects: self.enter_end_effects,
+            exit_end_effects: self.enter_start_effects,
+            enter_start_effects: self.exit_end_effects,
+            enter_end_effects: self.exit_start_effects,
+            exit_mode: self.enter_mode,
+            enter_mode: self.exit_mode,
+        }
+    }
 }
 
 #[derive(PartialEq, Clone)]
@@ -71,6 +292,9 @@ pub enum TransitionVariant {
     SlideFadeRotate,
     ScaleFadeFlip,
     RotateScaleSlide,
+
+    /// User-defined transition
+    Custom(Box<TransitionConfig>),
 }
 
 impl TransitionVariant {
@@ -78,12 +302,17 @@ impl TransitionVariant {
         let identity = Transform::identity();
 
         match self {
+            TransitionVariant::Custom(config) => (**config).clone(),
+
             TransitionVariant::SlideLeft => {
                 TransitionConfig {
                     exit_start: identity,                              // Start in place
                     exit_end: Transform::new(-100.0, 0.0, 1.0, 0.0),   // Exit left
                     enter_start: Transform::new(100.0, 0.0, 1.0, 0.0), // Enter from right
                     enter_end: identity,                               // End in place
+                    exit_end_effects: PageEffects::visible(),          // No fade
+                    enter_start_effects: PageEffects::visible(),       // No fade
+                    ..Default::default()
                 }
             }
 
@@ -93,6 +322,9 @@ impl TransitionVariant {
                     exit_end: Transform::new(100.0, 0.0, 1.0, 0.0),     // Exit right
                     enter_start: Transform::new(-100.0, 0.0, 1.0, 0.0), // Enter from left
                     enter_end: identity,                                // End in place
+                    exit_end_effects: PageEffects::visible(),           // No fade
+                    enter_start_effects: PageEffects::visible(),        // No fade
+                    ..Default::default()
                 }
             }
 
@@ -102,6 +334,9 @@ impl TransitionVariant {
                     exit_end: Transform::new(0.0, -100.0, 1.0, 0.0),   // Exit up
                     enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Enter from bottom
                     enter_end: identity,                               // End in place
+                    exit_end_effects: PageEffects::visible(),          // No fade
+                    enter_start_effects: PageEffects::visible(),       // No fade
+                    ..Default::default()
                 }
             }
 
@@ -111,6 +346,9 @@ impl TransitionVariant {
                     exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),     // Exit down
                     enter_start: Transform::new(0.0, -100.0, 1.0, 0.0), // Enter from top
                     enter_end: identity,                                // End in place
+                    exit_end_effects: PageEffects::visible(),           // No fade
+                    enter_start_effects: PageEffects::visible(),        // No fade
+                    ..Default::default()
                 }
             }
 
@@ -119,211 +357,322 @@ impl TransitionVariant {
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // Fade out completely
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0), // Start invisible
                 enter_end: identity,                             // Fade in completely
+                ..Default::default()
             },
             TransitionVariant::ScaleUp => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 0.0, 0.0),    // Shrink to nothing
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                exit_end_effects: PageEffects::visible(),        // No fade
+                enter_start_effects: PageEffects::visible(),     // No fade
+                ..Default::default()
             },
             TransitionVariant::ScaleDown => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 0.0),    // Grow to twice size
                 enter_start: Transform::new(0.0, 0.0, 2.0, 0.0), // Start twice size
                 enter_end: identity,                             // Shrink to full size
+                exit_end_effects: PageEffects::visible(),        // No fade
+                enter_start_effects: PageEffects::visible(),     // No fade
+                ..Default::default()
             },
             TransitionVariant::FlipHorizontal => TransitionConfig {
-                exit_start: identity,                               // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 180.0),     // Flip 180 degrees horizontally
-                enter_start: Transform::new(0.0, 0.0, 1.0, -180.0), // Start flipped 180 degrees horizontally
-                enter_end: identity,                                // End in place
+                exit_start: identity,
+                exit_end: identity,
+                enter_start: identity,
+                enter_end: identity,
+                exit_end_effects: PageEffects::visible().with_rotate_y(PI), // Flip 180 degrees horizontally
+                enter_start_effects: PageEffects::visible().with_rotate_y(-PI), // Start flipped 180 degrees horizontally
+                ..Default::default()
             },
             TransitionVariant::FlipVertical => TransitionConfig {
-                exit_start: identity,                               // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 180.0),     // Flip 180 degrees vertically
-                enter_start: Transform::new(0.0, 0.0, 1.0, -180.0), // Start flipped 180 degrees vertically
-                enter_end: identity,                                // End in place
+                exit_start: identity,
+                exit_end: identity,
+                enter_start: identity,
+                enter_end: identity,
+                exit_end_effects: PageEffects::visible().with_rotate_x(PI), // Flip 180 degrees vertically
+                enter_start_effects: PageEffects::visible().with_rotate_x(-PI), // Start flipped 180 degrees vertically
+                ..Default::default()
             },
             TransitionVariant::RotateLeft => TransitionConfig {
-                exit_start: identity,                              // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 90.0),     // Rotate 90 degrees to the left
-                enter_start: Transform::new(0.0, 0.0, 1.0, -90.0), // Start rotated 90 degrees to the right
-                enter_end: identity,                               // End in place
+                exit_start: identity,                                   // Start in place
+                exit_end: Transform::new(0.0, 0.0, 1.0, FRAC_PI_2), // Rotate 90 degrees to the left
+                enter_start: Transform::new(0.0, 0.0, 1.0, -FRAC_PI_2), // Start rotated 90 degrees to the right
+                enter_end: identity,                                    // End in place
+                exit_end_effects: PageEffects::visible(),               // No fade
+                enter_start_effects: PageEffects::visible(),            // No fade
+                ..Default::default()
             },
             TransitionVariant::RotateRight => TransitionConfig {
-                exit_start: identity,                             // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, -90.0),   // Rotate 90 degrees to the right
-                enter_start: Transform::new(0.0, 0.0, 1.0, 90.0), // Start rotated 90 degrees to the left
-                enter_end: identity,                              // End in place
+                exit_start: identity,                                  // Start in place
+                exit_end: Transform::new(0.0, 0.0, 1.0, -FRAC_PI_2), // Rotate 90 degrees to the right
+                enter_start: Transform::new(0.0, 0.0, 1.0, FRAC_PI_2), // Start rotated 90 degrees to the left
+                enter_end: identity,                                   // End in place
+                exit_end_effects: PageEffects::visible(),              // No fade
+                enter_start_effects: PageEffects::visible(),           // No fade
+                ..Default::default()
             },
             TransitionVariant::SlideUpFade => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, -100.0, 1.0, 0.0),   // Exit up
                 enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Enter from bottom
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::SlideDownFade => TransitionConfig {
                 exit_start: identity,                               // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),     // Exit down
                 enter_start: Transform::new(0.0, -100.0, 1.0, 0.0), // Enter from top
                 enter_end: identity,                                // End in place
+                ..Default::default()
             },
             TransitionVariant::ScaleUpFade => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 0.0, 0.0),    // Shrink to nothing
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::BounceIn => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),      // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Start from bottom
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::BounceOut => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0), // Start in place
                 enter_end: identity,                             // No change
+                ..Default::default()
             },
             TransitionVariant::ScaleDownFade => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 0.0),    // Grow to twice size
                 enter_start: Transform::new(0.0, 0.0, 2.0, 0.0), // Start twice size
                 enter_end: identity,                             // Shrink to full size
+                ..Default::default()
             },
             TransitionVariant::RotateLeftFade => TransitionConfig {
-                exit_start: identity,                              // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 90.0),     // Rotate 90 degrees to the left
-                enter_start: Transform::new(0.0, 0.0, 1.0, -90.0), // Start rotated 90 degrees to the right
-                enter_end: identity,                               // End in place
+                exit_start: identity,                                   // Start in place
+                exit_end: Transform::new(0.0, 0.0, 1.0, FRAC_PI_2), // Rotate 90 degrees to the left
+                enter_start: Transform::new(0.0, 0.0, 1.0, -FRAC_PI_2), // Start rotated 90 degrees to the right
+                enter_end: identity,                                    // End in place
+                ..Default::default()
             },
             TransitionVariant::RotateRightFade => TransitionConfig {
-                exit_start: identity,                             // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, -90.0),   // Rotate 90 degrees to the right
-                enter_start: Transform::new(0.0, 0.0, 1.0, 90.0), // Start rotated 90 degrees to the left
-                enter_end: identity,                              // End in place
+                exit_start: identity,                                  // Start in place
+                exit_end: Transform::new(0.0, 0.0, 1.0, -FRAC_PI_2), // Rotate 90 degrees to the right
+                enter_start: Transform::new(0.0, 0.0, 1.0, FRAC_PI_2), // Start rotated 90 degrees to the left
+                enter_end: identity,                                   // End in place
+                ..Default::default()
             },
             TransitionVariant::FlipHorizontalFade => TransitionConfig {
-                exit_start: identity,                               // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 180.0),     // Flip 180 degrees horizontally
-                enter_start: Transform::new(0.0, 0.0, 1.0, -180.0), // Start flipped 180 degrees horizontally
-                enter_end: identity,                                // End in place
+                exit_start: identity,
+                exit_end: identity,
+                enter_start: identity,
+                enter_end: identity,
+                exit_end_effects: PageEffects::hidden().with_rotate_y(PI), // Flip 180 degrees horizontally
+                enter_start_effects: PageEffects::hidden().with_rotate_y(-PI), // Start flipped 180 degrees horizontally
+                ..Default::default()
             },
             TransitionVariant::FlipVerticalFade => TransitionConfig {
-                exit_start: identity,                               // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 180.0),     // Flip 180 degrees vertically
-                enter_start: Transform::new(0.0, 0.0, 1.0, -180.0), // Start flipped 180 degrees vertically
-                enter_end: identity,                                // End in place
+                exit_start: identity,
+                exit_end: identity,
+                enter_start: identity,
+                enter_end: identity,
+                exit_end_effects: PageEffects::hidden().with_rotate_x(PI), // Flip 180 degrees vertically
+                enter_start_effects: PageEffects::hidden().with_rotate_x(-PI), // Start flipped 180 degrees vertically
+                ..Default::default()
             },
             TransitionVariant::ZoomIn => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::ZoomOut => TransitionConfig {
                 exit_start: identity,                          // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 0.0),  // Grow to twice size
                 enter_start: identity,                         // Start in place
                 enter_end: Transform::new(0.0, 0.0, 0.0, 0.0), // Shrink to full size
+                ..Default::default()
             },
             TransitionVariant::SlideDiagonalUpLeft => TransitionConfig {
                 exit_start: identity,                                // Start in place
                 exit_end: Transform::new(-100.0, -100.0, 1.0, 0.0),  // Exit up and left
                 enter_start: Transform::new(100.0, 100.0, 1.0, 0.0), // Enter from bottom right
                 enter_end: identity,                                 // End in place
+                exit_end_effects: PageEffects::visible(),            // No fade
+                enter_start_effects: PageEffects::visible(),         // No fade
+                ..Default::default()
             },
             TransitionVariant::SlideDiagonalUpRight => TransitionConfig {
                 exit_start: identity,                                 // Start in place
                 exit_end: Transform::new(100.0, -100.0, 1.0, 0.0),    // Exit up and right
                 enter_start: Transform::new(-100.0, 100.0, 1.0, 0.0), // Enter from bottom left
                 enter_end: identity,                                  // End in place
+                exit_end_effects: PageEffects::visible(),             // No fade
+                enter_start_effects: PageEffects::visible(),          // No fade
+                ..Default::default()
             },
             TransitionVariant::SlideDiagonalDownLeft => TransitionConfig {
                 exit_start: identity,                                 // Start in place
                 exit_end: Transform::new(-100.0, 100.0, 1.0, 0.0),    // Exit down and left
                 enter_start: Transform::new(100.0, -100.0, 1.0, 0.0), // Enter from top right
                 enter_end: identity,                                  // End in place
+                exit_end_effects: PageEffects::visible(),             // No fade
+                enter_start_effects: PageEffects::visible(),          // No fade
+                ..Default::default()
             },
             TransitionVariant::SlideDiagonalDownRight => TransitionConfig {
                 exit_start: identity,                                  // Start in place
                 exit_end: Transform::new(100.0, 100.0, 1.0, 0.0),      // Exit down and right
                 enter_start: Transform::new(-100.0, -100.0, 1.0, 0.0), // Enter from top left
                 enter_end: identity,                                   // End in place
+                exit_end_effects: PageEffects::visible(),              // No fade
+                enter_start_effects: PageEffects::visible(),           // No fade
+                ..Default::default()
             },
             TransitionVariant::SpiralIn => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::SpiralOut => TransitionConfig {
                 exit_start: identity,                          // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 0.0),  // Grow to twice size
                 enter_start: identity,                         // Start in place
                 enter_end: Transform::new(0.0, 0.0, 0.0, 0.0), // Shrink to full size
+                ..Default::default()
             },
             TransitionVariant::ElasticIn => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),      // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Start from bottom
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::ElasticOut => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0), // Start in place
                 enter_end: identity,                             // No change
+                ..Default::default()
             },
             TransitionVariant::SwingIn => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),      // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 0.0), // Start from bottom
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::SwingOut => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 0.0), // Start in place
                 enter_end: identity,                             // No change
+                ..Default::default()
             },
             TransitionVariant::SlideLeftFade => TransitionConfig {
                 exit_start: identity,                              // Start in place
                 exit_end: Transform::new(-100.0, 0.0, 1.0, 0.0),   // Exit left
                 enter_start: Transform::new(100.0, 0.0, 1.0, 0.0), // Enter from right
                 enter_end: identity,                               // End in place
+                ..Default::default()
             },
             TransitionVariant::SlideRightFade => TransitionConfig {
                 exit_start: identity,                               // Start in place
                 exit_end: Transform::new(100.0, 0.0, 1.0, 0.0),     // Exit right
                 enter_start: Transform::new(-100.0, 0.0, 1.0, 0.0), // Enter from left
                 enter_end: identity,                                // End in place
+                ..Default::default()
             },
             TransitionVariant::ScaleRotateFade => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::SlideFadeRotate => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::ScaleFadeFlip => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
             TransitionVariant::RotateScaleSlide => TransitionConfig {
                 exit_start: identity,                            // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                             // Grow to full size
+                ..Default::default()
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reversed_transition() {
+        let forward = TransitionVariant::SlideLeft.get_config();
+        let back = forward.clone().reversed();
+        // The current page slides back out to the right, the previous one returns from the left
+        assert_eq!(back.exit_start, forward.enter_end);
+        assert_eq!(back.exit_end, Transform::new(100.0, 0.0, 1.0, 0.0));
+        assert_eq!(back.enter_start, Transform::new(-100.0, 0.0, 1.0, 0.0));
+        assert_eq!(back.enter_end, forward.exit_start);
+        assert_eq!(back.clone().reversed().exit_end, forward.exit_end);
+    }
+
+    #[test]
+    fn test_transition_effects() {
+        // Plain slides keep both pages opaque, their fading variants don't
+        let slide = TransitionVariant::SlideUp.get_config();
+        assert_eq!(slide.exit_end_effects.opacity, 1.0);
+        assert_eq!(slide.enter_start_effects.opacity, 1.0);
+        let fade = TransitionVariant::SlideUpFade.get_config();
+        assert_eq!(fade.exit_end_effects.opacity, 0.0);
+        assert_eq!(fade.enter_start_effects.opacity, 0.0);
+
+        // Same for scales and rotations
+        let scale = TransitionVariant::ScaleUp.get_config();
+        let scale_fade = TransitionVariant::ScaleUpFade.get_config();
+        assert_eq!(scale.exit_end_effects.opacity, 1.0);
+        assert_eq!(scale_fade.exit_end_effects.opacity, 0.0);
+        assert!(scale != scale_fade);
+        let rotate = TransitionVariant::RotateLeft.get_config();
+        assert_eq!(rotate.enter_start_effects.opacity, 1.0);
+
+        let flip = TransitionVariant::FlipHorizontal.get_config();
+        assert_eq!(flip.exit_end_effects.rotate_y, PI);
+        assert_eq!(flip.reversed().enter_start_effects.rotate_y, PI);
+    }
+
+    #[test]
+    fn test_custom_transition() {
+        let branded = TransitionConfig {
+            enter_start: Transform::new(0.0, 40.0, 0.95, 0.0),
+            ..Default::default()
+        }
+        .with_mode(AnimationMode::Spring(Spring::gentle()));
+        let config = TransitionVariant::Custom(Box::new(branded.clone())).get_config();
+        assert!(config == branded);
+    }
+}